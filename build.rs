@@ -0,0 +1,26 @@
+use std::{env, process::Command};
+
+// Records metadata about the build, which is stored alongside benchmark timings.
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=LIB_AOC_RUSTC_VERSION={version}");
+
+    for (source, name) in [
+        ("OPT_LEVEL", "LIB_AOC_OPT_LEVEL"),
+        ("TARGET", "LIB_AOC_TARGET"),
+        ("CARGO_CFG_TARGET_FEATURE", "LIB_AOC_TARGET_FEATURES")
+    ] {
+        let value = env::var(source).unwrap_or_default();
+        println!("cargo:rustc-env={name}={value}");
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
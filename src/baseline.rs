@@ -0,0 +1,154 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    io,
+    path::Path,
+    time::Duration
+};
+
+use crate::build_info::BuildInfo;
+use crate::outcome::{Delta, Outcome, Phase};
+
+/// Benchmark timings from a previous run, used to measure changes in performance.
+/// 
+/// Stored on disk as plain text, one phase per line, preceded by a `#` line for
+/// each property of the build that recorded it. Phase names are escaped within paths,
+/// so that they can't be confused with separators.
+#[derive(Debug, Default)]
+pub(crate) struct Baseline {
+    // Keyed by day and the escaped path to the phase (e.g. "Part 1/build graph".)
+    timings: BTreeMap<(u8, String), Duration>,
+    // Empty for baselines saved by older versions.
+    build: BTreeMap<String, String>
+}
+
+impl Baseline {
+    /// Loads a baseline from disk; if the file does not exist, an empty baseline is returned.
+    pub(crate) fn load(path: &Path) -> io::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err)
+        };
+
+        let build = text
+            .lines()
+            .filter_map(|line| line.strip_prefix("#\t"))
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        let timings = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let day = fields.next()?.parse::<u8>().ok()?;
+                let nanos = fields.next()?.parse::<u64>().ok()?;
+                let path = fields.next()?.to_string();
+                Some(((day, path), Duration::from_nanos(nanos)))
+            })
+            .collect();
+
+        Ok(Self { timings, build })
+    }
+
+    /// Writes the baseline to disk, creating parent directories as necessary.
+    pub(crate) fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let header = BuildInfo::CURRENT
+            .fields()
+            .into_iter()
+            .map(|(key, value)| format!("#\t{key}\t{value}\n"));
+
+        let timings = self.timings
+            .iter()
+            .map(|((day, path), duration)| format!("{day}\t{}\t{path}\n", duration.as_nanos()));
+
+        let text: String = header
+            .chain(timings)
+            .collect();
+
+        fs::write(path, text)
+    }
+
+    /// Lists the ways in which the build that recorded the baseline differs from the specified one,
+    /// e.g. `opt-level: 3 -> 0`.
+    pub(crate) fn build_changes(&self, build: &BuildInfo) -> Vec<String> {
+        build
+            .fields()
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let recorded = self.build.get(key)?;
+                (recorded != value).then(|| format!("{key}: {recorded} -> {value}"))
+            })
+            .collect()
+    }
+
+    /// Replaces the timings recorded for the outcome's day.
+    pub(crate) fn record(&mut self, outcome: &Outcome<impl Display>) {
+        let day = outcome.day;
+        self.timings.retain(|key, _| key.0 != day);
+        self.timings.insert((day, TOTAL.to_string()), outcome.timings.total);
+
+        walk(&outcome.timings.phases, "", &mut |path, phase| {
+            self.timings.insert((day, path.to_string()), phase.duration);
+        });
+    }
+
+    /// Annotates the outcome's timings with their change relative to the baseline.
+    /// 
+    /// Changes smaller than `noise` percent are not considered significant.
+    pub(crate) fn compare(&self, outcome: &mut Outcome<impl Display>, noise: f64) {
+        let day = outcome.day;
+        let delta = |path: &str, duration: Duration| {
+            self.timings
+                .get(&(day, path.to_string()))
+                .map(|baseline| Delta::new(duration, *baseline, noise))
+        };
+
+        outcome.timings.delta = delta(TOTAL, outcome.timings.total);
+        walk_mut(&mut outcome.timings.phases, "", &mut |path, phase| {
+            phase.delta = delta(path, phase.duration);
+        });
+    }
+}
+
+// Not a valid phase path, since slashes in phase names are escaped.
+const TOTAL: &str = "/Total";
+
+/// Escapes the characters of a phase name that have special meaning in a baseline file: slashes,
+/// which separate the names in a path, and tabs and newlines, which separate fields and lines.
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '/' => escaped.push_str("\\/"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+fn walk(phases: &[Phase], prefix: &str, visit: &mut impl FnMut(&str, &Phase)) {
+    for phase in phases {
+        let path = format!("{prefix}{}", escape(&phase.name));
+        visit(&path, phase);
+        walk(&phase.children, &format!("{path}/"), visit);
+    }
+}
+
+fn walk_mut(phases: &mut [Phase], prefix: &str, visit: &mut impl FnMut(&str, &mut Phase)) {
+    for phase in phases {
+        let path = format!("{prefix}{}", escape(&phase.name));
+        visit(&path, phase);
+        walk_mut(&mut phase.children, &format!("{path}/"), visit);
+    }
+}
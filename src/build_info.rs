@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+/// Describes how the benchmarked code was compiled.
+/// 
+/// Benchmark timings are only comparable between identical builds, so this is stored alongside
+/// [`Timings`](crate::prelude::Timings) and baselines. Note that it describes the build of `lib_aoc`
+/// itself, which shares its profile, compiler and target with the rest of your binary
+/// (unless overriden per-package.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Whether or not debug assertions are enabled, which is the case for debug builds.
+    pub debug: bool,
+    /// The optimization level, e.g. `3`.
+    pub opt_level: &'static str,
+    /// The output of `rustc --version`.
+    pub rustc: &'static str,
+    /// The target triple, e.g. `x86_64-unknown-linux-gnu`.
+    pub target: &'static str,
+    /// The enabled target CPU features, separated by commas (e.g. `fxsr,sse,sse2`.)
+    pub target_features: &'static str
+}
+
+impl BuildInfo {
+    /// Metadata for the current build.
+    pub const CURRENT: Self = Self {
+        debug: cfg!(debug_assertions),
+        opt_level: env!("LIB_AOC_OPT_LEVEL"),
+        rustc: env!("LIB_AOC_RUSTC_VERSION"),
+        target: env!("LIB_AOC_TARGET"),
+        target_features: env!("LIB_AOC_TARGET_FEATURES")
+    };
+
+    /// Returns the profile the build most likely used, based on whether debug assertions are enabled.
+    pub fn profile(&self) -> &'static str {
+        match self.debug {
+            true => "debug",
+            false => "release"
+        }
+    }
+
+    /// Lists the build's properties as key-value pairs, in a stable order.
+    pub fn fields(&self) -> [(&'static str, &'static str); 5] {
+        [
+            ("profile", self.profile()),
+            ("opt-level", self.opt_level),
+            ("rustc", self.rustc),
+            ("target", self.target),
+            ("target-features", self.target_features)
+        ]
+    }
+}
+
+impl Default for BuildInfo {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
+impl Display for BuildInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, opt-level {}, {} ({})", self.profile(), self.opt_level, self.rustc, self.target)
+    }
+}
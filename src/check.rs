@@ -0,0 +1,334 @@
+use std::{
+    fmt::{Display, Write},
+    marker::PhantomData,
+    sync::mpsc,
+    thread,
+    time::Duration
+};
+use colored::Colorize;
+
+use crate::{Test, catch_quietly, execute_steps};
+use crate::constants::{PART_ONE, PART_TWO};
+use crate::duration::DurationFormat;
+use crate::outcome::{Answer, Outcome};
+use crate::report::plain_duration;
+
+/// The verdict of checking one part of a solution against its test case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The computed answer matched the expected one.
+    Passed,
+    /// The computed answer did not match the expected one.
+    Failed {
+        expected: String,
+        actual: String
+    },
+    /// Something panicked (e.g. test loading isn't implemented); contains the panic message.
+    Error(String),
+    /// The part is unimplemented or doesn't exist.
+    Skipped
+}
+
+/// Represents the result of checking one part of a solution against one of its [`Test`] examples,
+/// or against its [verified answer](crate::Solver::verified).
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    pub day: u8,
+    pub part: bool,
+    /// The index of the example, or `None` if the full puzzle input was checked against its verified answer.
+    pub example: Option<usize>,
+    pub verdict: Verdict,
+    /// The time spent parsing and solving the input.
+    pub duration: Duration
+}
+
+impl TestCase {
+    /// Returns a short, uncolored name for the test case, e.g. `Part 1, example 2`.
+    pub fn name(&self) -> String {
+        match self.example {
+            Some(example) => format!("Part {}, example {}", self.part as u8 + 1, example + 1),
+            None => format!("Part {}, verified", self.part as u8 + 1)
+        }
+    }
+
+    /// Returns `true` if the case failed or errored.
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Failed { .. } | Verdict::Error(_))
+    }
+}
+
+impl Display for TestCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.example {
+            Some(0) => format!("Test {}", self.part as u8 + 1),
+            Some(example) => format!("Test {} (example {})", self.part as u8 + 1, example + 1),
+            None => format!("Verify {}", self.part as u8 + 1)
+        };
+        let time = DurationFormat::current()
+            .width(0)
+            .format(self.duration);
+
+        match &self.verdict {
+            Verdict::Passed => write!(f, "{}: {} ({time})", name.bold(), "passed".green()),
+            Verdict::Failed { expected, actual } => write!(
+                f,
+                "{}: {} (expected {}, got {})",
+                name.bold(),
+                "failed".red().bold(),
+                expected.green(),
+                actual.red()
+            ),
+            Verdict::Error(message) => write!(f, "{}: {} ({message})", name.bold(), "error".red().bold()),
+            Verdict::Skipped => write!(f, "{}: {}", name.bold(), "skipped".dimmed())
+        }
+    }
+}
+
+/// Checks one part of a solution against each of its examples, capturing any panics.
+/// 
+/// With a `timeout`, each example is checked on a worker thread and abandoned if it exceeds the limit.
+#[doc(hidden)]
+pub fn execute_test<S, const DAY: u8>(part: bool, timeout: Option<Duration>) -> Vec<TestCase> where 
+    S: Test<DAY> + ?Sized + 'static,
+    S::Output: PartialEq
+{
+    let case = |example, verdict, duration| TestCase {
+        day: DAY,
+        part,
+        example: Some(example),
+        verdict,
+        duration
+    };
+
+    if part == PART_TWO && !S::HAS_PART_TWO {
+        return vec![case(0, Verdict::Skipped, Duration::ZERO)];
+    }
+
+    let examples = match catch_quietly(|| S::examples(part)) {
+        Ok(examples) if examples.is_empty() => return vec![case(0, Verdict::Skipped, Duration::ZERO)],
+        Ok(examples) => examples,
+        Err(message) => return vec![case(0, Verdict::Error(message), Duration::ZERO)]
+    };
+
+    let Some(limit) = timeout else {
+        return examples
+            .into_iter()
+            .enumerate()
+            .map(|(example, (input, expected))| {
+                let (verdict, duration) = check_example::<S, DAY>(part, &input, &expected);
+                case(example, verdict, duration)
+            })
+            .collect();
+    };
+
+    (0..examples.len())
+        .map(|example| {
+            let (sender, receiver) = mpsc::channel();
+
+            // Outputs needn't be `Send`, so the worker fetches its own copy of the example.
+            thread::spawn(move || {
+                let result = catch_quietly(|| S::examples(part).swap_remove(example))
+                    .map(|(input, expected)| check_example::<S, DAY>(part, &input, &expected));
+
+                // The receiver is gone if this worker was abandoned.
+                let _ = sender.send(result);
+            });
+
+            match receiver.recv_timeout(limit) {
+                Ok(Ok((verdict, duration))) => case(example, verdict, duration),
+                Ok(Err(message)) => case(example, Verdict::Error(message), Duration::ZERO),
+                Err(_) => case(example, Verdict::Error(format!("timed out after {}", plain_duration(limit))), limit)
+            }
+        })
+        .collect()
+}
+
+/// Checks one part of a solution against a single example, returning the verdict and the time spent.
+/// 
+/// The example is executed like any other puzzle input, so the time spent is the total of its [`Timings`](crate::prelude::Timings)
+/// (parsing and the checked part.)
+fn check_example<S, const DAY: u8>(part: bool, input: &str, expected: &S::Output) -> (Verdict, Duration) where
+    S: Test<DAY> + ?Sized,
+    S::Output: PartialEq
+{
+    let mut parts = [false; 2];
+    parts[part as usize] = true;
+
+    let result = catch_quietly(|| {
+        let mut outcome = Outcome::new(DAY);
+        execute_steps::<S, DAY>(input, parts, &mut |step| outcome.record(step));
+        outcome
+    });
+
+    let outcome = match result {
+        Ok(outcome) => outcome,
+        Err(message) => return (Verdict::Error(message), Duration::ZERO)
+    };
+
+    let answer = match part {
+        PART_ONE => outcome.part_one,
+        PART_TWO => outcome.part_two
+    };
+
+    let verdict = match answer {
+        Answer::Solved(actual) if actual == *expected => Verdict::Passed,
+        Answer::Solved(actual) => Verdict::Failed {
+            expected: expected.to_string(),
+            actual: actual.to_string()
+        },
+        Answer::Invalid(message) => Verdict::Error(format!("invalid input ({message})")),
+        Answer::TimedOut(_) | Answer::Unimplemented | Answer::Absent => Verdict::Skipped
+    };
+
+    (verdict, outcome.timings.total)
+}
+
+/// Checks one part of an outcome against its verified answer, if there is one.
+pub(crate) fn verify(outcome: &Outcome<String>, part: bool, verified: Option<String>) -> Option<TestCase> {
+    let verified = verified?;
+    let (answer, duration) = match part {
+        PART_ONE => (&outcome.part_one, Some(outcome.timings.part_one())),
+        PART_TWO => (&outcome.part_two, outcome.timings.part_two())
+    };
+
+    let verdict = match answer {
+        Answer::Solved(actual) if *actual == verified => Verdict::Passed,
+        Answer::Solved(actual) => Verdict::Failed {
+            expected: verified,
+            actual: actual.clone()
+        },
+        Answer::TimedOut(limit) => Verdict::Error(format!("timed out after {}", plain_duration(*limit))),
+        Answer::Invalid(message) => Verdict::Error(format!("invalid input ({message})")),
+        Answer::Unimplemented | Answer::Absent => Verdict::Skipped
+    };
+
+    Some(TestCase {
+        day: outcome.day,
+        part,
+        example: None,
+        verdict,
+        duration: outcome.timings.parsing() + duration.unwrap_or_default()
+    })
+}
+
+/// Renders test cases as a JUnit XML report, with one test suite per day.
+pub(crate) fn junit(cases: &[TestCase]) -> String {
+    let mut days: Vec<_> = cases
+        .iter()
+        .map(|case| case.day)
+        .collect();
+    days.dedup();
+
+    let count = |cases: &[&TestCase], verdict: fn(&Verdict) -> bool| cases
+        .iter()
+        .filter(|case| verdict(&case.verdict))
+        .count();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+
+    for day in days {
+        let suite: Vec<_> = cases
+            .iter()
+            .filter(|case| case.day == day)
+            .collect();
+
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"Day {day:02}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            suite.len(),
+            count(&suite, |verdict| matches!(verdict, Verdict::Failed { .. })),
+            count(&suite, |verdict| matches!(verdict, Verdict::Error(_))),
+            count(&suite, |verdict| matches!(verdict, Verdict::Skipped)),
+            suite.iter().map(|case| case.duration).sum::<Duration>().as_secs_f64()
+        );
+
+        for case in suite {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"day{day:02}\" name=\"{}\" time=\"{:.6}\"",
+                xml_escape(&case.name()),
+                case.duration.as_secs_f64()
+            );
+
+            let _ = match &case.verdict {
+                Verdict::Passed => writeln!(xml, "/>"),
+                Verdict::Failed { expected, actual } => writeln!(
+                    xml,
+                    ">\n      <failure message=\"expected {0}, got {1}\">expected: {0}\nactual: {1}</failure>\n    </testcase>",
+                    xml_escape(expected),
+                    xml_escape(actual)
+                ),
+                Verdict::Error(message) => writeln!(
+                    xml,
+                    ">\n      <error message=\"{}\"/>\n    </testcase>",
+                    xml_escape(message)
+                ),
+                Verdict::Skipped => writeln!(xml, ">\n      <skipped/>\n    </testcase>")
+            };
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c)
+        }
+    }
+
+    escaped
+}
+
+type TestFn = fn(bool, Option<Duration>) -> Vec<TestCase>;
+
+// The runner macro needs to know whether or not each day implements `Test`, without requiring it.
+// This is accomplished via autoref specialization: method resolution prefers `ProbeTest` (implemented
+// on `Probe` itself) when its bounds are satisfied, and otherwise falls back to `ProbeFallback` 
+// (implemented on `&Probe`.)
+
+#[doc(hidden)]
+pub struct Probe<S, const DAY: u8>(PhantomData<fn() -> S>);
+
+impl<S, const DAY: u8> Probe<S, DAY> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeTest {
+    fn test_fn(&self) -> Option<TestFn>;
+}
+
+impl<S, const DAY: u8> ProbeTest for Probe<S, DAY> where
+    S: Test<DAY> + 'static,
+    S::Output: PartialEq
+{
+    fn test_fn(&self) -> Option<TestFn> {
+        Some(execute_test::<S, DAY>)
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeFallback {
+    fn test_fn(&self) -> Option<TestFn>;
+}
+
+impl<S, const DAY: u8> ProbeFallback for &Probe<S, DAY> {
+    fn test_fn(&self) -> Option<TestFn> {
+        None
+    }
+}
@@ -0,0 +1,213 @@
+use std::{path::PathBuf, thread, time::Duration};
+
+use crate::color::ColorChoice;
+use crate::duration::{DurationFormat, TimeUnit};
+
+pub(crate) const USAGE: &str = "\
+usage: [DAY | progress | watch DAY | test [DAY] | new DAY] [OPTIONS]
+
+options:
+  --baseline <PATH>          Baseline file to compare timings against [default: target/lib_aoc/baseline]
+  --save-baseline            Save this run's timings to the baseline file
+  --noise <PERCENT>          Changes smaller than this are considered noise [default: 5]
+  --max-regression <PERCENT> Fail if any phase is slower than the baseline by more than this
+  --timeout <DURATION>       Abandon parsing or any part that runs longer than this (e.g. 10s, 500ms)
+  --jobs <N>                 Number of days to solve concurrently [default: available parallelism]
+  --serial                   Solve one day at a time, for accurate benchmarking (same as --jobs 1)
+  --rebuild                  In watch mode, also rebuild and restart when source files change
+  --format <FORMAT>          Output format: pretty, plain, json, markdown, table, quiet, or a custom reporter [default: pretty]
+  --readme <PATH>            With --format table, splice the table into this file between marker comments
+  --junit <PATH>             With test, also write a JUnit XML report to this file
+  --color <WHEN>             Whether to color output: auto, always or never [default: auto]
+  --precision <N>            Number of decimal places in durations [default: 2]
+  --unit <UNIT>              Unit to display durations in: auto, ns, us, ms or s [default: auto]
+  --template <PATH>          With new, generate the day's module from this template instead of the default
+  --debug-policy <POLICY>    When benchmarking a debug build: warn, refuse (to save baselines) or release (re-run in release mode) [default: warn]";
+
+/// The command requested on the command line.
+pub(crate) enum Command {
+    /// Solve a specific day, or every day if `None`.
+    Solve(Option<u8>),
+    /// Print a progress report.
+    Progress,
+    /// Re-run a day whenever its inputs change.
+    Watch(u8),
+    /// Check a specific day, or every day if `None`, against its examples and verified answers.
+    Test(Option<u8>),
+    /// Generate the module and input files for a new day.
+    New(u8)
+}
+
+/// What to do when benchmarking a debug build, whose timings are not representative.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DebugPolicy {
+    /// Print a warning.
+    Warn,
+    /// Print a warning, and refuse to save baselines.
+    Refuse,
+    /// Re-run the program in release mode.
+    Release
+}
+
+/// Options parsed from the command line.
+pub(crate) struct Options {
+    pub command: Command,
+    pub baseline: PathBuf,
+    pub save_baseline: bool,
+    pub noise: f64,
+    pub max_regression: Option<f64>,
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+    pub rebuild: bool,
+    pub format: String,
+    pub readme: Option<PathBuf>,
+    pub junit: Option<PathBuf>,
+    pub color: ColorChoice,
+    pub durations: DurationFormat,
+    pub debug_policy: DebugPolicy,
+    pub template: Option<PathBuf>
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            command: Command::Solve(None),
+            baseline: PathBuf::from("target/lib_aoc/baseline"),
+            save_baseline: false,
+            noise: 5.0,
+            max_regression: None,
+            timeout: None,
+            jobs: thread::available_parallelism().map_or(1, usize::from),
+            rebuild: false,
+            format: "pretty".to_string(),
+            readme: None,
+            junit: None,
+            color: ColorChoice::Auto,
+            durations: DurationFormat::new(),
+            debug_policy: DebugPolicy::Warn,
+            template: None
+        }
+    }
+}
+
+impl Options {
+    pub(crate) fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut positional = Vec::new();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            // Support both `--flag value` and `--flag=value`.
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if arg.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+                _ => (arg.clone(), None)
+            };
+
+            let mut value = || inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("'{flag}' requires a value."));
+
+            match flag.as_str() {
+                "--baseline" => options.baseline = value()?.into(),
+                "--save-baseline" => options.save_baseline = true,
+                "--noise" => options.noise = percentage(&value()?)?,
+                "--max-regression" => options.max_regression = Some(percentage(&value()?)?),
+                "--timeout" => options.timeout = Some(duration(&value()?)?),
+                "--jobs" => options.jobs = value()?
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|jobs| *jobs > 0)
+                    .ok_or_else(|| format!("'{flag}' requires a positive integer."))?,
+                "--serial" => options.jobs = 1,
+                "--rebuild" => options.rebuild = true,
+                "--format" => options.format = value()?,
+                "--readme" => options.readme = Some(value()?.into()),
+                "--junit" => options.junit = Some(value()?.into()),
+                "--color" => options.color = match value()?.as_str() {
+                    "auto" => ColorChoice::Auto,
+                    "always" => ColorChoice::Always,
+                    "never" => ColorChoice::Never,
+                    other => return Err(format!("'{other}' is not a valid color choice; expected auto, always or never."))
+                },
+                "--precision" => options.durations = options.durations.precision(value()?
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|precision| *precision <= 9)
+                    .ok_or_else(|| format!("'{flag}' requires an integer between 0 and 9."))?),
+                "--unit" => options.durations = options.durations.unit(match value()?.as_str() {
+                    "auto" => None,
+                    "ns" => Some(TimeUnit::Nanos),
+                    "us" | "µs" => Some(TimeUnit::Micros),
+                    "ms" => Some(TimeUnit::Millis),
+                    "s" => Some(TimeUnit::Seconds),
+                    other => return Err(format!("'{other}' is not a valid unit; expected auto, ns, us, ms or s."))
+                }),
+                "--template" => options.template = Some(value()?.into()),
+                "--debug-policy" => options.debug_policy = match value()?.as_str() {
+                    "warn" => DebugPolicy::Warn,
+                    "refuse" => DebugPolicy::Refuse,
+                    "release" => DebugPolicy::Release,
+                    other => return Err(format!("'{other}' is not a valid debug policy; expected warn, refuse or release."))
+                },
+                _ if flag.starts_with('-') => return Err(format!("unknown option '{flag}'.")),
+                _ => positional.push(flag)
+            }
+        }
+
+        let positional: Vec<_> = positional
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        options.command = match positional[..] {
+            [] => Command::Solve(None),
+            ["progress"] => Command::Progress,
+            ["watch", day] => Command::Watch(parse_day(day)?),
+            ["watch"] => return Err("'watch' requires a day.".to_string()),
+            ["test"] => Command::Test(None),
+            ["test", day] => Command::Test(Some(parse_day(day)?)),
+            ["new", day] => Command::New(parse_day(day)?),
+            ["new"] => return Err("'new' requires a day.".to_string()),
+            [day] => Command::Solve(Some(parse_day(day)?)),
+            [.., unexpected] => return Err(format!("unexpected argument '{unexpected}'."))
+        };
+
+        Ok(options)
+    }
+}
+
+fn percentage(value: &str) -> Result<f64, String> {
+    value
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .ok_or_else(|| format!("'{value}' is not a valid percentage."))
+}
+
+/// Parses a duration with an optional unit suffix (`ms`, `s` or `m`); bare numbers are seconds.
+fn duration(value: &str) -> Result<Duration, String> {
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value, 1.0)
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * scale).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("'{value}' is not a valid duration."))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .map_err(|_| format!("'{value}' is not a valid day or command."))
+}
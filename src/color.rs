@@ -0,0 +1,77 @@
+use std::{
+    env,
+    fmt::Display,
+    io::{self, IsTerminal}
+};
+use colored::control;
+
+/// Determines whether output is colored with ANSI escape codes.
+/// 
+/// Colors apply to everything `lib_aoc` displays, including answers, timings and headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color output if standard output is a terminal, unless overriden by the environment:
+    /// `CLICOLOR_FORCE` (set to anything but `0`) enables colors, and otherwise `NO_COLOR` 
+    /// or `CLICOLOR=0` disable them.
+    #[default]
+    Auto,
+    /// Always color output.
+    Always,
+    /// Never color output.
+    Never
+}
+
+impl ColorChoice {
+    /// Resolves the choice against the environment, returning `true` if output should be colored.
+    pub fn should_colorize(self) -> bool {
+        let set = |name| env::var_os(name).is_some_and(|value| !value.is_empty());
+        let enabled = |name| env::var_os(name).is_none_or(|value| value != "0");
+
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto if set("CLICOLOR_FORCE") && enabled("CLICOLOR_FORCE") => true,
+            Self::Auto if set("NO_COLOR") || !enabled("CLICOLOR") => false,
+            Self::Auto => io::stdout().is_terminal()
+        }
+    }
+
+    /// Applies the choice to all subsequent output.
+    pub fn apply(self) {
+        control::set_override(self.should_colorize());
+    }
+}
+
+/// Renders a value as text without any colors, regardless of the current [`ColorChoice`].
+/// 
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let answer: Answer<u64> = Answer::Solved(24_000);
+/// assert_eq!(plain_text(&answer), "24000");
+/// assert_eq!(plain_text("\x1b[1;32m24000\x1b[0m"), "24000");
+/// ```
+pub fn plain_text(value: &(impl Display + ?Sized)) -> String {
+    strip_escapes(&value.to_string())
+}
+
+/// Removes ANSI escape sequences (such as colors) from text.
+/// 
+/// Stripping the rendered text, rather than disabling colors while rendering, leaves the
+/// process-wide [`ColorChoice`] untouched for other threads.
+fn strip_escapes(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // Control sequences end with a byte in the range `@` to `~`.
+            '\x1b' if chars.clone().next() == Some('[') => {
+                chars.by_ref().skip(1).find(|c| ('@'..='~').contains(c));
+            },
+            c => plain.push(c)
+        }
+    }
+
+    plain
+}
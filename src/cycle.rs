@@ -0,0 +1,165 @@
+//! Cycle detection, for simulations that run for far more steps than could ever be executed.
+//!
+//! A simulation is described by its initial state and a function which computes the next state. Once a state repeats,
+//! every later state can be found by skipping over whole loops of the [`Cycle`].
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::cycle;
+//! // Repeatedly squaring modulo 1000 eventually loops.
+//! let square = |&n: &u64| n * n % 1000;
+//!
+//! let (found, states) = cycle::find(7, square);
+//! assert_eq!(found, cycle::brent(7, square));
+//! assert_eq!(states.len(), found.start + found.length + 1);
+//!
+//! let mut n = 7;
+//! for _ in 0..1_000 {
+//!     n = square(&n);
+//! }
+//! assert_eq!(cycle::state_at(7, 1_000, square), n);
+//! ```
+
+use std::{
+    collections::HashMap,
+    hash::Hash
+};
+
+/// Where a sequence of states starts repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The step at which the first repeated state occurs.
+    pub start: usize,
+    /// The number of steps before a state repeats.
+    pub length: usize
+}
+
+impl Cycle {
+    /// Maps step `n` onto the earliest step with the same state, which is before the end of the first loop.
+    pub fn reduce(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The number of whole loops completed by step `n`.
+    pub fn loops(&self, n: usize) -> usize {
+        n.saturating_sub(self.start) / self.length
+    }
+}
+
+/// Steps through states until one repeats, returning the cycle along with every state up to and including the first repeat.
+///
+/// States are remembered in a hash map; use [`brent`] if they are too large or can't be hashed.
+pub fn find<S>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) where
+    S: Clone + Eq + Hash
+{
+    find_by_key(initial, step, S::clone)
+}
+
+/// Like [`find`], but states are considered equal if they have the same key.
+///
+/// This is useful when only part of the state determines what happens next - for example, the shape of the top of a
+/// tower of falling blocks, but not its height. See [`extrapolate`] for predicting the rest of the state.
+pub fn find_by_key<S, K>(initial: S, mut step: impl FnMut(&S) -> S, mut key: impl FnMut(&S) -> K) -> (Cycle, Vec<S>) where
+    K: Eq + Hash
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+
+    loop {
+        let current = states.len() - 1;
+
+        if let Some(start) = seen.insert(key(&states[current]), current) {
+            let cycle = Cycle {
+                start,
+                length: current - start
+            };
+            return (cycle, states);
+        }
+
+        let next = step(&states[current]);
+        states.push(next);
+    }
+}
+
+/// Finds the cycle using Brent's algorithm, which only keeps two states in memory at a time.
+///
+/// This calls `step` more often than [`find`], so is only preferable when states can't be hashed or are expensive to store.
+pub fn brent<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle where
+    S: Clone + PartialEq
+{
+    // Find the length by teleporting the tortoise to the hare at every power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then find the start by moving two states a whole loop apart in lockstep.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Returns the state after `n` steps, skipping over any whole loops.
+pub fn state_at<S>(initial: S, n: usize, step: impl FnMut(&S) -> S) -> S where
+    S: Clone + Eq + Hash
+{
+    let (cycle, mut states) = find(initial, step);
+    states.swap_remove(cycle.reduce(n))
+}
+
+/// Returns the value derived from the state after `n` steps, assuming it changes by the same amount in every loop.
+///
+/// States are compared by `key`, as with [`find_by_key`]; `value` extracts the part of the state that keeps growing.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::cycle;
+/// // A tower grows by 1, 2 and then 3 units, over and over.
+/// let step = |&(phase, height): &(usize, i64)| ((phase + 1) % 3, height + [1, 2, 3][phase]);
+///
+/// let height = cycle::extrapolate((0, 0), 1_000_000_000_000, step, |&(phase, _)| phase, |&(_, height)| height);
+/// assert_eq!(height, 1_999_999_999_999);
+/// ```
+pub fn extrapolate<S, K>(
+    initial: S,
+    n: usize,
+    step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> i64
+) -> i64 where
+    K: Eq + Hash
+{
+    let (cycle, states) = find_by_key(initial, step, key);
+
+    if n < states.len() {
+        return value(&states[n]);
+    }
+
+    let per_loop = value(&states[cycle.start + cycle.length]) - value(&states[cycle.start]);
+    value(&states[cycle.reduce(n)]) + cycle.loops(n) as i64 * per_loop
+}
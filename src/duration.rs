@@ -0,0 +1,195 @@
+use std::{
+    fmt::Display,
+    sync::RwLock,
+    time::Duration
+};
+use colored::Colorize;
+
+static CURRENT: RwLock<DurationFormat> = RwLock::new(DurationFormat::new());
+
+/// A unit of time that durations can be displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Seconds
+}
+
+impl TimeUnit {
+    /// Picks the largest unit in which the duration is at least one.
+    pub fn fitting(duration: Duration) -> Self {
+        match duration.as_nanos() {
+            0..1_000 => Self::Nanos,
+            1_000..1_000_000 => Self::Micros,
+            1_000_000..1_000_000_000 => Self::Millis,
+            _ => Self::Seconds
+        }
+    }
+
+    /// The unit's symbol, e.g. `ms`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Nanos => "ns",
+            Self::Micros => "µs",
+            Self::Millis => "ms",
+            Self::Seconds => "s"
+        }
+    }
+
+    /// The next larger unit, if there is one.
+    fn larger(self) -> Option<Self> {
+        match self {
+            Self::Nanos => Some(Self::Micros),
+            Self::Micros => Some(Self::Millis),
+            Self::Millis => Some(Self::Seconds),
+            Self::Seconds => None
+        }
+    }
+
+    fn nanos(self) -> f64 {
+        match self {
+            Self::Nanos => 1.0,
+            Self::Micros => 1e3,
+            Self::Millis => 1e6,
+            Self::Seconds => 1e9
+        }
+    }
+}
+
+/// Controls how durations are displayed, e.g. in the benchmark output.
+///
+/// The format used by everything `lib_aoc` displays can be changed using [`apply`](DurationFormat::apply),
+/// or from the command line with the [`Runner`](crate::prelude::Runner)'s `--precision` and `--unit` options.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// # use std::time::Duration;
+/// let format = DurationFormat::new().precision(1);
+/// assert_eq!(format.plain(Duration::from_nanos(7_223)), "7.2 µs");
+/// assert_eq!(format.plain(Duration::from_nanos(20)), "20.0 ns");
+/// assert_eq!(format.plain(Duration::from_nanos(999_960)), "1.0 ms");
+///
+/// let format = format.unit(TimeUnit::Millis).width(6);
+/// assert_eq!(format.plain(Duration::from_micros(1_500)), "   1.5 ms");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat {
+    precision: usize,
+    unit: Option<TimeUnit>,
+    width: usize
+}
+
+impl DurationFormat {
+    /// Creates the default format: two decimal places, scaled to the most fitting unit, and unaligned.
+    pub const fn new() -> Self {
+        Self {
+            precision: 2,
+            unit: None,
+            width: 0
+        }
+    }
+
+    /// Returns the format currently used by everything `lib_aoc` displays.
+    pub fn current() -> Self {
+        *CURRENT.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Uses this format for everything `lib_aoc` subsequently displays.
+    pub fn apply(self) {
+        *CURRENT.write().unwrap_or_else(|err| err.into_inner()) = self;
+    }
+
+    /// Sets the number of decimal places.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets a fixed unit, or `None` to pick the most fitting unit for each duration.
+    pub fn unit(mut self, unit: impl Into<Option<TimeUnit>>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Right-aligns the number to (at least) the given width.
+    ///
+    /// Units are padded to a common width as well, so that aligned durations line up in columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Splits a duration into its formatted (but unpadded) number and unit.
+    pub fn split(&self, duration: Duration) -> (String, TimeUnit) {
+        let round = |unit: TimeUnit| format!("{:.1$}", duration.as_nanos() as f64 / unit.nanos(), self.precision);
+
+        let Some(unit) = self.unit else {
+            // Rounding can carry a value up to 1000 (e.g. 999.999 µs), in which case the next unit fits better.
+            let mut unit = TimeUnit::fitting(duration);
+            let mut value = round(unit);
+
+            while let Some(larger) = unit.larger().filter(|_| value.parse::<f64>().is_ok_and(|value| value >= 1000.0)) {
+                unit = larger;
+                value = round(unit);
+            }
+
+            return (value, unit);
+        };
+
+        (round(unit), unit)
+    }
+
+    /// Formats a duration, coloring its unit.
+    pub fn format(&self, duration: Duration) -> FormattedDuration {
+        let (value, unit) = self.split(duration);
+
+        FormattedDuration {
+            value,
+            unit,
+            width: self.width
+        }
+    }
+
+    /// Formats a duration without any colors.
+    pub fn plain(&self, duration: Duration) -> String {
+        let (value, unit) = self.split(duration);
+
+        match self.width {
+            0 => format!("{value} {}", unit.symbol()),
+            width => format!("{value:>width$} {:<2}", unit.symbol())
+        }
+    }
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A duration formatted by a [`DurationFormat`].
+pub struct FormattedDuration {
+    value: String,
+    unit: TimeUnit,
+    width: usize
+}
+
+impl Display for FormattedDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self.width {
+            0 => self.unit.symbol().to_string(),
+            _ => format!("{:<2}", self.unit.symbol())
+        };
+
+        let symbol = match self.unit {
+            TimeUnit::Nanos => symbol.cyan(),
+            TimeUnit::Micros => symbol.green(),
+            TimeUnit::Millis => symbol.yellow(),
+            TimeUnit::Seconds => symbol.red()
+        };
+
+        write!(f, "{:>1$} {symbol}", self.value, self.width)
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    any::type_name,
+    ops::Range
+};
+
+use crate::grid::Grid;
+
+/// Puzzle text that can be searched byte by byte: either a `str` or a `[u8]`.
+///
+/// Helpers that return pieces of their input return them as the same type.
+pub trait Text {
+    /// The underlying bytes.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Returns the text in the given byte range, which must lie on ASCII boundaries.
+    fn slice(&self, range: Range<usize>) -> &Self;
+}
+
+impl Text for str {
+    fn as_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn slice(&self, range: Range<usize>) -> &Self {
+        &self[range]
+    }
+}
+
+impl Text for [u8] {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn slice(&self, range: Range<usize>) -> &Self {
+        &self[range]
+    }
+}
+
+/// A primitive integer type, which can be extracted from text using [`ints`].
+pub trait FromDigits: Sized {
+    /// Whether a leading `-` is considered part of the number.
+    const SIGNED: bool;
+
+    /// Converts a run of ASCII digits into a number, returning `None` if it doesn't fit.
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_from_digits {
+    ($signed:literal: $($int:ty),*) => {
+        $(
+            impl FromDigits for $int {
+                const SIGNED: bool = $signed;
+
+                fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+                    digits
+                        .iter()
+                        .map(|digit| (digit - b'0') as Self)
+                        .try_fold(0 as Self, |value, digit| match negative {
+                            true => value.checked_mul(10)?.checked_sub(digit),
+                            false => value.checked_mul(10)?.checked_add(digit)
+                        })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_digits!(true: i8, i16, i32, i64, i128, isize);
+impl_from_digits!(false: u8, u16, u32, u64, u128, usize);
+
+/// Iterates over every integer in the text, ignoring everything else.
+///
+/// A number is a run of ASCII digits. For signed types, a directly preceding `-` makes it negative,
+/// unless the `-` itself follows a letter or digit - so ranges like `1-3` yield `1` and `3`.
+///
+/// # Panics
+/// If a number does not fit in the requested type.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let line = "Sensor at x=-2, y=15: closest beacon is at x=10, y=-16";
+/// assert_eq!(ints::<i64>(line).collect::<Vec<_>>(), [-2, 15, 10, -16]);
+/// assert_eq!(ints::<u32>("2-4,6-8".as_bytes()).collect::<Vec<_>>(), [2, 4, 6, 8]);
+/// assert_eq!(ints::<i32>("2-4,6-8").collect::<Vec<_>>(), [2, 4, 6, 8]);
+/// ```
+pub fn ints<N: FromDigits>(text: &(impl Text + ?Sized)) -> impl Iterator<Item = N> + '_ {
+    let bytes = text.as_bytes();
+    let mut position = 0;
+
+    std::iter::from_fn(move || {
+        let start = position + bytes[position..].iter().position(u8::is_ascii_digit)?;
+        let end = bytes[start..]
+            .iter()
+            .position(|byte| !byte.is_ascii_digit())
+            .map_or(bytes.len(), |length| start + length);
+
+        position = end;
+
+        let negative = N::SIGNED
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+
+        let digits = &bytes[start..end];
+        let number = N::from_digits(negative, digits).unwrap_or_else(|| panic!(
+            "`{}{}` does not fit in {}",
+            if negative { "-" } else { "" },
+            String::from_utf8_lossy(digits),
+            type_name::<N>()
+        ));
+
+        Some(number)
+    })
+}
+
+/// Iterates over the value of every ASCII digit in the text, ignoring everything else.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// assert_eq!(digits("a1b2c3d").sum::<u8>(), 6);
+/// ```
+pub fn digits(text: &(impl Text + ?Sized)) -> impl Iterator<Item = u8> + '_ {
+    text.as_bytes()
+        .iter()
+        .filter(|byte| byte.is_ascii_digit())
+        .map(|byte| byte - b'0')
+}
+
+/// Iterates over the blocks of lines in the text that are separated by blank lines.
+///
+/// Blocks don't include their final line break, and any number of blank (or whitespace-only) lines can separate them.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let puzzle = "1000\n2000\n\n4000\r\n\r\n\n5000\n6000\n";
+/// let elves: Vec<u32> = blocks(puzzle)
+///     .map(|block| ints::<u32>(block).sum())
+///     .collect();
+///
+/// assert_eq!(blocks(puzzle).next(), Some("1000\n2000"));
+/// assert_eq!(elves, [3000, 4000, 11000]);
+/// ```
+pub fn blocks<T: Text + ?Sized>(text: &T) -> impl Iterator<Item = &T> + '_ {
+    let bytes = text.as_bytes();
+    let is_blank = |line: &Range<usize>| bytes[line.clone()].iter().all(u8::is_ascii_whitespace);
+    let mut lines = lines(bytes).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(is_blank).is_some() {}
+
+        let first = lines.next()?;
+        let mut end = first.end;
+
+        while let Some(line) = lines.next_if(|line| !is_blank(line)) {
+            end = line.end;
+        }
+
+        Some(text.slice(first.start..end))
+    })
+}
+
+/// Iterates over the `key<separator>value` pairs in the text, such as `ecl:gry` or `x=3`.
+///
+/// Pairs are delimited by whitespace (including line breaks), commas or semicolons, and are split at the first
+/// occurrence of the separator. Anything without a separator is skipped.
+///
+/// # Panics
+/// If the separator is not an ASCII character.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let passport = "ecl:gry pid:860033327\nhcl:#fffffd byr:1937 skip";
+/// let fields: Vec<_> = key_value_pairs(passport, ':').collect();
+///
+/// assert_eq!(fields[0], ("ecl", "gry"));
+/// assert_eq!(fields[2], ("hcl", "#fffffd"));
+/// assert_eq!(fields.len(), 4);
+/// ```
+pub fn key_value_pairs<T: Text + ?Sized>(text: &T, separator: char) -> impl Iterator<Item = (&T, &T)> + '_ {
+    assert!(separator.is_ascii(), "Separator should be an ASCII character.");
+    let separator = separator as u8;
+    let bytes = text.as_bytes();
+    let is_delimiter = |byte: &u8| byte.is_ascii_whitespace() || matches!(byte, b',' | b';');
+    let mut position = 0;
+
+    std::iter::from_fn(move || loop {
+        let start = position + bytes[position..].iter().position(|byte| !is_delimiter(byte))?;
+        let end = bytes[start..]
+            .iter()
+            .position(is_delimiter)
+            .map_or(bytes.len(), |length| start + length);
+
+        position = end;
+
+        if let Some(split) = bytes[start..end].iter().position(|&byte| byte == separator) {
+            let split = start + split;
+            return Some((text.slice(start..split), text.slice(split + 1..end)));
+        }
+    })
+}
+
+/// Parses a grid of single digits, such as a height map, into their values. Trailing blank lines are ignored.
+///
+/// # Panics
+/// If the grid contains anything other than digits, or its lines are not all the same length.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let grid = digit_grid("303\n255\n");
+/// assert_eq!(grid[(1, 1)], 5);
+/// assert_eq!(grid.height(), 2);
+/// assert_eq!(digit_grid("12\n34\n\n").height(), 2);
+/// ```
+pub fn digit_grid(text: &(impl Text + ?Sized)) -> Grid<u8> {
+    let bytes = text.as_bytes();
+    let is_blank = |line: &Range<usize>| bytes[line.clone()].iter().all(u8::is_ascii_whitespace);
+
+    let mut lines: Vec<_> = lines(bytes).collect();
+    while lines.last().is_some_and(is_blank) {
+        lines.pop();
+    }
+
+    let rows = lines
+        .into_iter()
+        .map(|line| {
+            bytes[line]
+                .iter()
+                .map(|&byte| {
+                    assert!(byte.is_ascii_digit(), "Grid should only contain digits, found `{}`.", byte as char);
+                    byte - b'0'
+                })
+                .collect()
+        })
+        .collect();
+
+    Grid::from_rows(rows)
+}
+
+/// Iterates over the byte range of each line's contents, like [`str::lines`].
+fn lines(bytes: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut position = 0;
+
+    std::iter::from_fn(move || {
+        if position >= bytes.len() {
+            return None;
+        }
+
+        let start = position;
+        let end = bytes[start..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(bytes.len(), |length| start + length);
+
+        position = end + 1;
+
+        match bytes[start..end].last() {
+            Some(b'\r') => Some(start..end - 1),
+            _ => Some(start..end)
+        }
+    })
+}
@@ -0,0 +1,564 @@
+//! Points, directions and distances in two and three dimensions, as well as hexagonal grids.
+//!
+//! Points are generic over any primitive [`Integer`] type. As in [`grid`](crate::grid), `y` increases downwards,
+//! so [`North`](Direction::North) is towards smaller `y` values. Points can be used to index into a
+//! [`Grid`](crate::grid::Grid) or [`SparseGrid`](crate::grid::SparseGrid) directly.
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::geom::{Direction, Point2};
+//! let mut position = Point2::new(0i64, 0);
+//! let mut facing = Direction::North;
+//!
+//! for (turn, distance) in [('R', 2), ('L', 3)] {
+//!     facing = match turn {
+//!         'L' => facing.turn_left(),
+//!         _ => facing.turn_right()
+//!     };
+//!     position += Point2::from(facing) * distance;
+//! }
+//!
+//! assert_eq!(position, Point2::new(2, -3));
+//! assert_eq!(position.manhattan(Point2::default()), 5);
+//! ```
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}
+};
+
+use crate::grid::Coordinate;
+
+/// A primitive integer type, which can be used as the components of a point.
+pub trait Integer: Copy + Ord + Default + Hash + Debug + Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// Adds a small signed offset, returning `None` on overflow.
+    fn checked_offset(self, offset: i8) -> Option<Self>;
+
+    /// Converts to `usize`, returning `None` if the value is negative or too large.
+    fn to_usize(self) -> Option<usize>;
+
+    /// Returns the absolute difference between two values.
+    fn distance(self, other: Self) -> Self {
+        match self > other {
+            true => self - other,
+            false => other - self
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    (signed: $($int:ty),*) => {
+        $(
+            impl Integer for $int {
+                fn checked_offset(self, offset: i8) -> Option<Self> {
+                    self.checked_add(offset.into())
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )*
+    };
+    (unsigned: $($int:ty => $signed:ty),*) => {
+        $(
+            impl Integer for $int {
+                fn checked_offset(self, offset: i8) -> Option<Self> {
+                    self.checked_add_signed(<$signed>::from(offset))
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned: u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+/// One of the eight compass directions.
+///
+/// `y` increases towards the [`South`](Direction::South), as is conventional for puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The four diagonal directions, clockwise from north-east.
+    pub const DIAGONAL: [Self; 4] = [Self::NorthEast, Self::SouthEast, Self::SouthWest, Self::NorthWest];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest
+    ];
+
+    /// Parses a direction from a character commonly used in puzzle inputs: `^>v<`, `NESW` or `URDL`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' | 'U' => Some(Self::North),
+            '>' | 'E' | 'R' => Some(Self::East),
+            'v' | 'S' | 'D' => Some(Self::South),
+            '<' | 'W' | 'L' => Some(Self::West),
+            _ => None
+        }
+    }
+
+    /// The change in `x` and `y` when moving one step in this direction.
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1)
+        }
+    }
+
+    /// Rotates by `eighths` of a full turn clockwise.
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Turns 90 degrees to the left (counterclockwise.)
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 90 degrees to the right (clockwise.)
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 45 degrees to the left (counterclockwise.)
+    pub fn turn_left_45(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turns 45 degrees to the right (clockwise.)
+    pub fn turn_right_45(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Returns the opposite direction.
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns `true` for north, east, south and west.
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+}
+
+/// A point (or vector) in two dimensions.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let grid = Grid::parse("ab\ncd", |c| c);
+/// let corner = Point2::new(1usize, 1);
+///
+/// assert_eq!(grid[corner], 'd');
+/// assert_eq!(grid.get(corner.step(Direction::North)), Some(&'b'));
+/// assert_eq!(corner.neighbours8().count(), 8);
+/// assert_eq!(Point2::new(0u8, 0).neighbours8().count(), 3);
+///
+/// let mut sparse = SparseGrid::new();
+/// sparse.insert(Point2::new(-2i32, 5), '#');
+/// assert!(sparse.contains((-2, 5)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T: Integer> Point2<T> {
+    /// Creates a new point.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// The Manhattan (taxicab) distance to another point: the sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The Chebyshev (chessboard) distance to another point: the largest distance along any axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// Moves one step in a direction, returning `None` if that would overflow.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        Some(Self::new(self.x.checked_offset(dx)?, self.y.checked_offset(dy)?))
+    }
+
+    /// Moves one step in a direction.
+    ///
+    /// # Panics
+    /// If the step would overflow (e.g. moving north from `y = 0` with an unsigned type.)
+    pub fn step(self, direction: Direction) -> Self {
+        self.checked_step(direction)
+            .expect("Stepping should not overflow")
+    }
+
+    /// Iterates over the (up to four) orthogonally adjacent points, clockwise from north.
+    ///
+    /// Points that would overflow are skipped.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// Iterates over the (up to eight) orthogonally and diagonally adjacent points, clockwise from north.
+    ///
+    /// Points that would overflow are skipped.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+}
+
+/// A point (or vector) in three dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T: Integer> Point3<T> {
+    /// Creates a new point.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The Manhattan (taxicab) distance to another point: the sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    /// The Chebyshev (chessboard) distance to another point: the largest distance along any axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    /// Iterates over the (up to six) points that share a face with this one.
+    ///
+    /// Points that would overflow are skipped.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::geom::Point3;
+    /// let cube = Point3::new(1u8, 1, 0);
+    /// assert_eq!(cube.neighbours6().count(), 5);
+    /// assert!(cube.neighbours6().all(|other| cube.manhattan(other) == 1));
+    /// ```
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        const OFFSETS: [(i8, i8, i8); 6] = [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)];
+
+        OFFSETS
+            .into_iter()
+            .filter_map(move |(dx, dy, dz)| Some(Self::new(
+                self.x.checked_offset(dx)?,
+                self.y.checked_offset(dy)?,
+                self.z.checked_offset(dz)?
+            )))
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Integer> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Integer> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Integer> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scale: T) -> Self {
+                Self { $($field: self.$field * scale),* }
+            }
+        }
+
+        impl<T: Integer + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Integer> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Integer> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+/// Converts a direction into a unit vector.
+impl<T: Integer + From<i8>> From<Direction> for Point2<T> {
+    fn from(direction: Direction) -> Self {
+        let (dx, dy) = direction.offset();
+        Self::new(dx.into(), dy.into())
+    }
+}
+
+impl<T: Integer> Coordinate for Point2<T> {
+    fn column_row(self) -> Option<(usize, usize)> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+}
+
+// Allows points to be used as positions in a `SparseGrid`, for every type that fits into an `i64`.
+macro_rules! impl_sparse_position {
+    ($($int:ty),*) => {
+        $(
+            impl From<Point2<$int>> for (i64, i64) {
+                fn from(point: Point2<$int>) -> Self {
+                    (point.x.into(), point.y.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_sparse_position!(i8, i16, i32, u8, u16, u32);
+
+/// A position on a hexagonal grid, in axial coordinates.
+///
+/// The third cube coordinate, `s`, is implied by `q + r + s = 0`. Hexagons can be stepped
+/// between using either [`FlatDirection`] or [`PointyDirection`], depending on the grid's orientation.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::geom::{Hex, FlatDirection};
+/// let path = "ne,ne,s,s";
+/// let end = path
+///     .split(',')
+///     .map(|step| step.parse::<FlatDirection>().unwrap())
+///     .fold(Hex::default(), Hex::step);
+///
+/// assert_eq!(end.distance(Hex::default()), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64
+}
+
+impl Hex {
+    /// Creates a hexagon from axial coordinates.
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a hexagon from cube coordinates.
+    ///
+    /// # Panics
+    /// If the coordinates do not sum to zero.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates should sum to zero.");
+        Self { q, r }
+    }
+
+    /// The implied third cube coordinate.
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Returns the cube coordinates `(q, r, s)`.
+    pub fn cube(self) -> (i64, i64, i64) {
+        (self.q, self.r, self.s())
+    }
+
+    /// The number of steps between two hexagons.
+    pub fn distance(self, other: Self) -> i64 {
+        let (q, r, s) = (self - other).cube();
+        (q.abs() + r.abs() + s.abs()) / 2
+    }
+
+    /// Moves one step in a direction.
+    pub fn step(self, direction: impl Into<Hex>) -> Self {
+        self + direction.into()
+    }
+
+    /// Returns the six adjacent hexagons.
+    pub fn neighbours(self) -> [Self; 6] {
+        FlatDirection::ALL.map(|direction| self.step(direction))
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Self::new(self.q * scale, self.r * scale)
+    }
+}
+
+macro_rules! hex_directions {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $symbol:literal ($q:literal, $r:literal)),* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            /// All six directions, clockwise.
+            pub const ALL: [Self; 6] = [$(Self::$variant),*];
+        }
+
+        /// Converts a direction into the offset of one step.
+        impl From<$name> for Hex {
+            fn from(direction: $name) -> Self {
+                match direction {
+                    $($name::$variant => Hex::new($q, $r)),*
+                }
+            }
+        }
+
+        /// Parses a direction from its lowercase abbreviation, e.g. `ne`.
+        impl std::str::FromStr for $name {
+            type Err = String;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                match text {
+                    $($symbol => Ok(Self::$variant),)*
+                    _ => Err(format!("'{text}' is not a valid direction."))
+                }
+            }
+        }
+    };
+}
+
+hex_directions! {
+    /// A direction on a hexagonal grid whose hexagons have flat tops, so that columns are straight.
+    FlatDirection {
+        North = "n" (0, -1),
+        NorthEast = "ne" (1, -1),
+        SouthEast = "se" (1, 0),
+        South = "s" (0, 1),
+        SouthWest = "sw" (-1, 1),
+        NorthWest = "nw" (-1, 0)
+    }
+}
+
+hex_directions! {
+    /// A direction on a hexagonal grid whose hexagons have pointy tops, so that rows are straight.
+    PointyDirection {
+        East = "e" (1, 0),
+        SouthEast = "se" (0, 1),
+        SouthWest = "sw" (-1, 1),
+        West = "w" (-1, 0),
+        NorthWest = "nw" (0, -1),
+        NorthEast = "ne" (1, -1)
+    }
+}
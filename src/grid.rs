@@ -0,0 +1,658 @@
+//! Two-dimensional grids, as found in many puzzle inputs.
+//!
+//! [`Grid`] is a dense, fixed-size grid, while [`SparseGrid`] is unbounded (for simulations that grow over time.)
+//!
+//! Positions in a [`Grid`] are given as `(x, y)` pairs, where `x` is the column and `y` is the row,
+//! with `(0, 0)` in the top-left corner. Anything implementing [`Coordinate`] can be used
+//! as a position, including signed pairs; positions outside the grid are simply out of bounds.
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::grid::Grid;
+//! let grid = Grid::parse("#..\n.#.\n..#", |c| c == '#');
+//!
+//! assert_eq!(grid.width(), 3);
+//! assert_eq!(grid.get((1, 1)), Some(&true));
+//! assert_eq!(grid.get((-1, 0)), None);
+//! assert_eq!(grid.iter().filter(|(_, wall)| **wall).count(), 3);
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::{Index, IndexMut}
+};
+
+/// A position that can be used to index into a [`Grid`].
+pub trait Coordinate {
+    /// Converts the position into a column and row, or `None` if either is negative or too large.
+    fn column_row(self) -> Option<(usize, usize)>;
+}
+
+impl Coordinate for (usize, usize) {
+    fn column_row(self) -> Option<(usize, usize)> {
+        Some(self)
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($int:ty),*) => {
+        $(
+            impl Coordinate for ($int, $int) {
+                fn column_row(self) -> Option<(usize, usize)> {
+                    Some((usize::try_from(self.0).ok()?, usize::try_from(self.1).ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(u8, u16, u32, u64, i8, i16, i32, i64, isize);
+
+// Offsets to orthogonal neighbours, clockwise from up.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Offsets to all neighbours, clockwise from up.
+const ALL: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A dense, rectangular grid of cells, stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid from puzzle text, converting each character into a cell.
+    ///
+    /// Each line becomes a row; trailing newlines (including blank lines) are ignored.
+    ///
+    /// # Panics
+    /// If the lines are not all the same length.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::Grid;
+    /// let grid = Grid::parse("ab\ncd\n\n", |c| c);
+    /// assert_eq!((grid.width(), grid.height()), (2, 2));
+    /// assert_eq!(grid[(1, 1)], 'd');
+    /// ```
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(text.len());
+
+        for line in text.trim_end_matches(['\n', '\r']).lines() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+
+            let length = cells.len() - start;
+            assert_eq!(*width.get_or_insert(length), length, "Grid rows should all have the same length.");
+        }
+
+        let width = width.unwrap_or(0);
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width
+        };
+
+        Self { width, height, cells }
+    }
+
+    /// Creates a grid by calling a function with each position, row by row.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Self { width, height, cells }
+    }
+
+    /// Creates a grid from a list of rows.
+    ///
+    /// # Panics
+    /// If the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(rows.iter().all(|row| row.len() == width), "Grid rows should all have the same length.");
+
+        let cells = rows
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Self { width, height, cells }
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `true` if the position is inside the grid.
+    pub fn contains(&self, position: impl Coordinate) -> bool {
+        self.offset(position).is_some()
+    }
+
+    fn offset(&self, position: impl Coordinate) -> Option<usize> {
+        let (x, y) = position.column_row()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        (offset % self.width, offset / self.width)
+    }
+
+    /// Returns the cell at the position, or `None` if it is out of bounds.
+    pub fn get(&self, position: impl Coordinate) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    /// Returns a mutable reference to the cell at the position, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, position: impl Coordinate) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Iterates over every position and its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.position(offset), cell))
+    }
+
+    /// Iterates over every position and a mutable reference to its cell, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset % width, offset / width), cell))
+    }
+
+    /// Iterates over every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over the (up to four) orthogonal neighbours of a position and their cells, clockwise from up.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::Grid;
+    /// let grid = Grid::parse("123\n456\n789", |c| c.to_digit(10).unwrap());
+    ///
+    /// let around: Vec<_> = grid.neighbours4((0, 0)).map(|(_, digit)| *digit).collect();
+    /// assert_eq!(around, [2, 4]);
+    ///
+    /// let sum: u32 = grid.neighbours8((1, 1)).map(|(_, digit)| digit).sum();
+    /// assert_eq!(sum, 40);
+    /// ```
+    pub fn neighbours4(&self, position: impl Coordinate) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(position, &ORTHOGONAL)
+    }
+
+    /// Iterates over the (up to eight) orthogonal and diagonal neighbours of a position and their cells, clockwise from up.
+    pub fn neighbours8(&self, position: impl Coordinate) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(position, &ALL)
+    }
+
+    fn neighbours(
+        &self,
+        position: impl Coordinate,
+        offsets: &'static [(isize, isize)]
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let origin = position.column_row();
+
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| {
+                let (x, y) = origin?;
+                let neighbour = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+                Some((neighbour, self.get(neighbour)?))
+            })
+    }
+
+    /// Returns a row as a slice.
+    ///
+    /// # Panics
+    /// If the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {y} is out of bounds.");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks` panics on a zero chunk size, so special-case empty grids.
+        self.cells
+            .chunks(self.width.max(1))
+            .take(self.height)
+    }
+
+    /// Iterates over the cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    /// If the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "Column {x} is out of bounds.");
+
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width)
+    }
+
+    /// Iterates over the columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over the diagonals running from top-left to bottom-right, starting with the bottom-left corner.
+    ///
+    /// Each diagonal's cells are given from top to bottom.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::Grid;
+    /// let grid = Grid::parse("abc\ndef", |c| c);
+    /// let text = |lines: Vec<String>| lines.join(" ");
+    ///
+    /// assert_eq!(text(grid.diagonals().map(|d| d.collect()).collect()), "d ae bf c");
+    /// assert_eq!(text(grid.anti_diagonals().map(|d| d.collect()).collect()), "a bd ce f");
+    /// assert_eq!(text(grid.columns().map(|c| c.collect()).collect()), "ad be cf");
+    /// ```
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        (1 - height..width).map(move |start| {
+            (0..height)
+                .map(move |y| (start + y, y))
+                .filter(move |(x, _)| (0..width).contains(x))
+                .map(|position| &self[position])
+        })
+    }
+
+    /// Iterates over the diagonals running from top-right to bottom-left, starting with the top-left corner.
+    ///
+    /// Each diagonal's cells are given from top to bottom.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        (0..width + height - 1).map(move |start| {
+            (0..height)
+                .map(move |y| (start - y, y))
+                .filter(move |(x, _)| (0..width).contains(x))
+                .map(|position| &self[position])
+        })
+    }
+
+    /// Returns the position of the first cell (row by row) that satisfies the predicate.
+    pub fn position_of(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.position(offset))
+    }
+
+    /// Returns the position of the first cell (row by row) equal to the value.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::Grid;
+    /// let grid = Grid::parse("S.#\n.#E", |c| c);
+    ///
+    /// assert_eq!(grid.find(&'E'), Some((2, 1)));
+    /// assert_eq!(grid.find_all(&'#').collect::<Vec<_>>(), [(2, 0), (1, 1)]);
+    /// assert_eq!(grid.to_string(), "S.#\n.#E");
+    /// ```
+    pub fn find(&self, value: &T) -> Option<(usize, usize)> where T: PartialEq {
+        self.position_of(|cell| cell == value)
+    }
+
+    /// Iterates over the positions of every cell equal to the value, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a where T: PartialEq {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Creates a new grid by applying a function to each cell.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect()
+        }
+    }
+
+    /// Returns the grid flipped over its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Returns the grid rotated by a quarter turn clockwise.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::Grid;
+    /// let grid = Grid::parse("ab\ncd\nef", |c| c);
+    ///
+    /// assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+    /// assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace");
+    /// assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+    /// ```
+    pub fn rotate_clockwise(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Returns the grid rotated by a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(self.width - 1 - y, x)].clone())
+    }
+}
+
+impl<T, C: Coordinate> Index<C> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: C) -> &Self::Output {
+        let offset = self.offset(position).expect("Position should be inside the grid");
+        &self.cells[offset]
+    }
+}
+
+impl<T, C: Coordinate> IndexMut<C> for Grid<T> {
+    fn index_mut(&mut self, position: C) -> &mut Self::Output {
+        let offset = self.offset(position).expect("Position should be inside the grid");
+        &mut self.cells[offset]
+    }
+}
+
+/// Renders each cell in turn, with rows separated by newlines.
+///
+/// For a grid of characters, this reproduces the text it was [parsed](Grid::parse) from
+/// (without a trailing newline.)
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An unbounded grid, storing only the cells that have been set.
+///
+/// Positions are signed `(x, y)` pairs, and can be negative. The grid tracks the bounding box of its cells,
+/// which is used when [rendering](SparseGrid::render) it.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::grid::SparseGrid;
+/// // A blinker from Conway's Game of Life.
+/// let mut grid: SparseGrid<()> = [(0, 0), (1, 0), (2, 0)].into_iter().map(|p| (p, ())).collect();
+///
+/// grid = grid.step(|_, cell, neighbours| match (cell, neighbours) {
+///     (Some(_), 2 | 3) | (None, 3) => Some(()),
+///     _ => None
+/// });
+///
+/// assert_eq!(grid.bounds(), Some(((1, -1), (1, 1))));
+/// assert_eq!(grid.render(|cell| if cell.is_some() { '#' } else { '.' }), "#\n#\n#");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    // The inclusive minimum and maximum corners, if there are any cells.
+    bounds: Option<((i64, i64), (i64, i64))>
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid.
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None
+        }
+    }
+
+    /// Parses a grid from puzzle text, with the first character at `(0, 0)`.
+    ///
+    /// Characters for which the function returns `None` are left empty, and trailing newlines are ignored.
+    ///
+    /// Example usage:
+    /// ```
+    /// # use lib_aoc::grid::SparseGrid;
+    /// let mut grid = SparseGrid::parse("#.\n.#", |c| (c == '#').then_some('#'));
+    /// assert_eq!(grid.len(), 2);
+    ///
+    /// grid.insert((-1, 2), 'o');
+    /// assert_eq!(grid.to_string(), ".#.\n..#\no..");
+    ///
+    /// grid.remove((-1, 2));
+    /// assert_eq!(grid.bounds(), Some(((0, 0), (1, 1))));
+    /// ```
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Self {
+        text.trim_end_matches(['\n', '\r'])
+            .lines()
+            .zip(0..)
+            .flat_map(|(line, y)| line.chars().zip(0..).map(move |(c, x)| ((x, y), c)))
+            .filter_map(|(position, c)| Some((position, cell(c)?)))
+            .collect()
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if no cells are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the inclusive minimum and maximum corners of the bounding box of every set cell,
+    /// or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        self.bounds
+    }
+
+    /// Returns `true` if the cell at the position is set.
+    pub fn contains(&self, position: impl Into<(i64, i64)>) -> bool {
+        self.cells.contains_key(&position.into())
+    }
+
+    /// Returns the cell at the position, if it is set.
+    pub fn get(&self, position: impl Into<(i64, i64)>) -> Option<&T> {
+        self.cells.get(&position.into())
+    }
+
+    /// Returns a mutable reference to the cell at the position, if it is set.
+    pub fn get_mut(&mut self, position: impl Into<(i64, i64)>) -> Option<&mut T> {
+        self.cells.get_mut(&position.into())
+    }
+
+    /// Sets the cell at the position, returning its previous value.
+    pub fn insert(&mut self, position: impl Into<(i64, i64)>, value: T) -> Option<T> {
+        let (x, y) = position.into();
+
+        self.bounds = Some(match self.bounds {
+            Some(((min_x, min_y), (max_x, max_y))) => ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+            None => ((x, y), (x, y))
+        });
+
+        self.cells.insert((x, y), value)
+    }
+
+    /// Clears the cell at the position, returning its value.
+    pub fn remove(&mut self, position: impl Into<(i64, i64)>) -> Option<T> {
+        let position = position.into();
+        let value = self.cells.remove(&position)?;
+
+        // Only cells on the edge of the bounding box can shrink it.
+        if let Some(((min_x, min_y), (max_x, max_y))) = self.bounds {
+            if [min_x, max_x].contains(&position.0) || [min_y, max_y].contains(&position.1) {
+                self.recompute_bounds();
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Keeps only the cells for which the predicate returns `true`.
+    pub fn retain(&mut self, mut predicate: impl FnMut((i64, i64), &mut T) -> bool) {
+        self.cells.retain(|position, value| predicate(*position, value));
+        self.recompute_bounds();
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.cells
+            .keys()
+            .fold(None, |bounds, &(x, y)| Some(match bounds {
+                Some(((min_x, min_y), (max_x, max_y))) => ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+                None => ((x, y), (x, y))
+            }));
+    }
+
+    /// Iterates over every set cell and its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.cells
+            .iter()
+            .map(|(position, value)| (*position, value))
+    }
+
+    /// Iterates over the positions of every set cell, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.cells
+            .keys()
+            .copied()
+    }
+
+    /// Iterates over the set orthogonal neighbours of a position and their cells, clockwise from up.
+    pub fn neighbours4(&self, position: impl Into<(i64, i64)>) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.neighbours(position.into(), &ORTHOGONAL)
+    }
+
+    /// Iterates over the set orthogonal and diagonal neighbours of a position and their cells, clockwise from up.
+    pub fn neighbours8(&self, position: impl Into<(i64, i64)>) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.neighbours(position.into(), &ALL)
+    }
+
+    fn neighbours(
+        &self,
+        (x, y): (i64, i64),
+        offsets: &'static [(isize, isize)]
+    ) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        offsets
+            .iter()
+            .map(move |&(dx, dy)| (x + dx as i64, y + dy as i64))
+            .filter_map(|neighbour| Some((neighbour, self.cells.get(&neighbour)?)))
+    }
+
+    /// Computes the next generation of a cellular automaton.
+    ///
+    /// The rule is called once for every set cell and every position next to one (including diagonally),
+    /// with the position, its current cell, and the number of set cells among its eight neighbours. It returns
+    /// the position's cell in the next generation.
+    pub fn step(&self, mut rule: impl FnMut((i64, i64), Option<&T>, usize) -> Option<T>) -> Self {
+        let candidates: HashSet<_> = self.cells
+            .keys()
+            .flat_map(|&(x, y)| ALL
+                .iter()
+                .map(move |&(dx, dy)| (x + dx as i64, y + dy as i64))
+                .chain([(x, y)]))
+            .collect();
+
+        candidates
+            .into_iter()
+            .filter_map(|position| {
+                let neighbours = self.neighbours8(position).count();
+                Some((position, rule(position, self.cells.get(&position), neighbours)?))
+            })
+            .collect()
+    }
+
+    /// Renders the bounding box of the grid as text, converting each position's cell (if any) into a character.
+    pub fn render(&self, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds else {
+            return String::new();
+        };
+
+        (min_y..=max_y)
+            .map(|y| (min_x..=max_x)
+                .map(|x| cell(self.cells.get(&(x, y))))
+                .collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P: Into<(i64, i64)>> FromIterator<(P, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, P: Into<(i64, i64)>> Extend<(P, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (position, value) in iter {
+            self.insert(position, value);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+/// Renders the bounding box of the grid, with each cell in turn and `.` for empty positions.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds else {
+            return Ok(());
+        };
+
+        for y in min_y..=max_y {
+            if y > min_y {
+                writeln!(f)?;
+            }
+
+            for x in min_x..=max_x {
+                match self.cells.get(&(x, y)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
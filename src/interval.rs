@@ -0,0 +1,482 @@
+//! Sets of integer intervals, and boxes in any number of dimensions.
+//!
+//! Puzzles about ranges (seed maps, sensor coverage, reactor reboots) tend to involve far too many values to store
+//! individually. [`IntervalSet`] stores a set of integers as disjoint intervals, while [`CuboidSet`] does the same for
+//! points in `N` dimensions using disjoint [`Cuboid`]s.
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::prelude::*;
+//! let mut covered = IntervalSet::new();
+//! covered.insert(-2..=2);
+//! covered.insert(2..=14);
+//! covered.insert(16..=24);
+//!
+//! assert_eq!(covered.len(), 26);
+//! assert_eq!(covered.gaps(0..=20).iter().collect::<Vec<_>>(), [15..16]);
+//! ```
+
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::math::Int;
+
+/// Converts any bounded range into a half-open range.
+fn half_open<T: Int>(range: impl RangeBounds<T>) -> Range<T> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + T::ONE,
+        Bound::Unbounded => panic!("Intervals should have a lower bound")
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + T::ONE,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => panic!("Intervals should have an upper bound")
+    };
+
+    start..end
+}
+
+/// A set of integers, stored as sorted, disjoint intervals.
+///
+/// Any bounded range (such as `2..5` or `2..=4`) can be inserted or removed. Intervals are always returned as half-open
+/// ranges, and adjacent intervals are merged.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let mut set = IntervalSet::from_iter([0..10, 20..30]);
+/// set.remove(5..25);
+///
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [0..5, 25..30]);
+/// assert!(set.contains(27));
+/// assert!(!set.contains(10));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>
+}
+
+impl<T: Int> IntervalSet<T> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Returns `true` if the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + (interval.end - interval.start))
+    }
+
+    /// The smallest range containing every value in the set, if it isn't empty.
+    pub fn span(&self) -> Option<Range<T>> {
+        let first = self.intervals.first()?;
+        let last = self.intervals.last()?;
+        Some(first.start..last.end)
+    }
+
+    /// Returns `true` if the set contains the value.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.start <= value)
+    }
+
+    /// Iterates over the disjoint intervals in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.intervals.iter().cloned()
+    }
+
+    /// Adds every value in the range to the set, merging any intervals it overlaps or touches.
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let Range { mut start, mut end } = half_open(range);
+        if start >= end {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|interval| interval.end < start);
+        let last = self.intervals.partition_point(|interval| interval.start <= end);
+
+        if first < last {
+            start = start.min(self.intervals[first].start);
+            end = end.max(self.intervals[last - 1].end);
+        }
+
+        self.intervals.splice(first..last, [start..end]);
+    }
+
+    /// Removes every value in the range from the set, splitting any interval it falls inside.
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let Range { start, end } = half_open(range);
+        if start >= end {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|interval| interval.end <= start);
+        let last = self.intervals.partition_point(|interval| interval.start < end);
+
+        if first >= last {
+            return;
+        }
+
+        let before = self.intervals[first].start..start;
+        let after = end..self.intervals[last - 1].end;
+
+        let remaining = [before, after]
+            .into_iter()
+            .filter(|interval| interval.start < interval.end);
+
+        self.intervals.splice(first..last, remaining);
+    }
+
+    /// Returns the values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|interval| union.insert(interval));
+        union
+    }
+
+    /// Returns the values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                intervals.push(start..end);
+            }
+
+            // Whichever interval ends first can't overlap anything else.
+            match a.end <= b.end {
+                true => left.next(),
+                false => right.next()
+            };
+        }
+
+        Self { intervals }
+    }
+
+    /// Returns the values in this set but not the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.iter().for_each(|interval| difference.remove(interval));
+        difference
+    }
+
+    /// Returns the values in the range that are *not* in the set.
+    pub fn gaps(&self, range: impl RangeBounds<T>) -> Self {
+        let mut gaps = Self::new();
+        gaps.insert(range);
+        gaps.difference(self)
+    }
+}
+
+impl<T: Int, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<T: Int, R: RangeBounds<T>> Extend<R> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, ranges: I) {
+        ranges
+            .into_iter()
+            .for_each(|range| self.insert(range));
+    }
+}
+
+/// An axis-aligned box of integer points in `N` dimensions, such as a rectangle or cuboid.
+///
+/// Like a half-open range, the box includes its `min` corner but not its `max` corner; use
+/// [`inclusive`](Cuboid::inclusive) for boxes whose far corner is included, as they usually are in puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub min: [T; N],
+    pub max: [T; N]
+}
+
+impl<T: Int, const N: usize> Cuboid<T, N> {
+    /// Creates a box from its included `min` and excluded `max` corners.
+    pub fn new(min: [T; N], max: [T; N]) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a box from two corners that are both included.
+    pub fn inclusive(min: [T; N], max: [T; N]) -> Self {
+        Self { min, max: max.map(|value| value + T::ONE) }
+    }
+
+    /// Returns `true` if the box contains no points.
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.min[axis] >= self.max[axis])
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> T {
+        match self.is_empty() {
+            true => T::ZERO,
+            false => (0..N).fold(T::ONE, |volume, axis| volume * (self.max[axis] - self.min[axis]))
+        }
+    }
+
+    /// Returns `true` if the box contains the point.
+    pub fn contains(&self, point: [T; N]) -> bool {
+        (0..N).all(|axis| self.min[axis] <= point[axis] && point[axis] < self.max[axis])
+    }
+
+    /// Returns the points in both boxes, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self {
+            min: std::array::from_fn(|axis| self.min[axis].max(other.min[axis])),
+            max: std::array::from_fn(|axis| self.max[axis].min(other.max[axis]))
+        };
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Splits the points in this box but not the other into (at most `2 * N`) disjoint boxes.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return match self.is_empty() {
+                true => Vec::new(),
+                false => vec![*self]
+            };
+        };
+
+        // Slice off the parts before and after the overlap along each axis in turn, narrowing the remainder each time.
+        let mut pieces = Vec::new();
+        let mut remainder = *self;
+
+        for axis in 0..N {
+            let mut before = remainder;
+            before.max[axis] = overlap.min[axis];
+
+            let mut after = remainder;
+            after.min[axis] = overlap.max[axis];
+
+            pieces.extend([before, after].into_iter().filter(|piece| !piece.is_empty()));
+
+            remainder.min[axis] = overlap.min[axis];
+            remainder.max[axis] = overlap.max[axis];
+        }
+
+        pieces
+    }
+
+    /// Iterates over every point in the box.
+    pub fn points(&self) -> impl Iterator<Item = [T; N]> + '_ {
+        let mut next = (!self.is_empty()).then_some(self.min);
+
+        std::iter::from_fn(move || {
+            let current = next?;
+            let mut point = current;
+
+            // Advance like an odometer, with the last axis changing fastest.
+            next = (0..N).rev().find_map(|axis| {
+                point[axis] = point[axis] + T::ONE;
+                match point[axis] < self.max[axis] {
+                    true => Some(point),
+                    false => {
+                        point[axis] = self.min[axis];
+                        None
+                    }
+                }
+            });
+
+            Some(current)
+        })
+    }
+}
+
+/// A set of points in `N` dimensions, stored as disjoint [`Cuboid`]s.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let mut reactor = CuboidSet::new();
+/// reactor.insert(Cuboid::inclusive([10, 10, 10], [12, 12, 12]));
+/// reactor.insert(Cuboid::inclusive([11, 11, 11], [13, 13, 13]));
+/// reactor.remove(Cuboid::inclusive([9, 9, 9], [11, 11, 11]));
+/// reactor.insert(Cuboid::inclusive([10, 10, 10], [10, 10, 10]));
+///
+/// assert_eq!(reactor.volume(), 39);
+/// assert!(reactor.contains([13, 13, 13]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuboidSet<T, const N: usize> {
+    cuboids: Vec<Cuboid<T, N>>
+}
+
+impl<T: Int, const N: usize> CuboidSet<T, N> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self { cuboids: Vec::new() }
+    }
+
+    /// Returns `true` if the set contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    /// The number of points in the set.
+    pub fn volume(&self) -> T {
+        self.cuboids
+            .iter()
+            .fold(T::ZERO, |total, cuboid| total + cuboid.volume())
+    }
+
+    /// Returns `true` if the set contains the point.
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.cuboids
+            .iter()
+            .any(|cuboid| cuboid.contains(point))
+    }
+
+    /// Iterates over the disjoint cuboids in the set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Cuboid<T, N>> + '_ {
+        self.cuboids.iter()
+    }
+
+    /// Adds every point in the cuboid to the set.
+    pub fn insert(&mut self, cuboid: Cuboid<T, N>) {
+        if cuboid.is_empty() {
+            return;
+        }
+
+        self.remove(cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    /// Removes every point in the cuboid from the set, splitting any cuboids it overlaps.
+    pub fn remove(&mut self, cuboid: Cuboid<T, N>) {
+        self.cuboids = self.cuboids
+            .iter()
+            .flat_map(|existing| existing.difference(&cuboid))
+            .collect();
+    }
+}
+
+impl<T: Int, const N: usize> Default for CuboidSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Int, const N: usize> FromIterator<Cuboid<T, N>> for CuboidSet<T, N> {
+    fn from_iter<I: IntoIterator<Item = Cuboid<T, N>>>(cuboids: I) -> Self {
+        let mut set = Self::new();
+        set.extend(cuboids);
+        set
+    }
+}
+
+impl<T: Int, const N: usize> Extend<Cuboid<T, N>> for CuboidSet<T, N> {
+    fn extend<I: IntoIterator<Item = Cuboid<T, N>>>(&mut self, cuboids: I) {
+        cuboids
+            .into_iter()
+            .for_each(|cuboid| self.insert(cuboid));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+
+    /// A xorshift generator, so that failures are reproducible.
+    struct Random(u32);
+
+    impl Random {
+        /// Returns a value in `min..max`.
+        fn range(&mut self, min: i64, max: i64) -> i64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            min + (self.0 as i64).rem_euclid(max - min)
+        }
+    }
+
+    #[test]
+    fn interval_set_matches_brute_force() {
+        let mut random = Random(0x2545_f491);
+        let values = |set: &IntervalSet<i64>| set.iter().flatten().collect::<BTreeSet<_>>();
+
+        for _ in 0..200 {
+            let (mut set, mut other) = (IntervalSet::new(), IntervalSet::new());
+            let (mut expected, mut expected_other) = (BTreeSet::new(), BTreeSet::new());
+
+            for _ in 0..8 {
+                let (start, length) = (random.range(-20, 20), random.range(0, 12));
+                match random.range(0, 3) {
+                    0 | 1 => {
+                        set.insert(start..=start + length);
+                        expected.extend(start..=start + length);
+                    },
+                    _ => {
+                        set.remove(start..start + length);
+                        (start..start + length).for_each(|value| { expected.remove(&value); });
+                    }
+                }
+
+                let (start, length) = (random.range(-20, 20), random.range(0, 12));
+                other.insert(start..start + length);
+                expected_other.extend(start..start + length);
+            }
+
+            assert_eq!(values(&set), expected);
+            assert_eq!(set.len() as usize, expected.len());
+            assert!((-25..25).all(|value| set.contains(value) == expected.contains(&value)));
+            assert!(set.iter().zip(set.iter().skip(1)).all(|(a, b)| a.end < b.start), "intervals should be merged");
+
+            assert_eq!(values(&set.union(&other)), &expected | &expected_other);
+            assert_eq!(values(&set.intersection(&other)), &expected & &expected_other);
+            assert_eq!(values(&set.difference(&other)), &expected - &expected_other);
+            assert_eq!(values(&set.gaps(-5..5)), &(-5..5).collect() - &expected);
+        }
+    }
+
+    #[test]
+    fn cuboid_set_matches_brute_force() {
+        let mut random = Random(0x9e37_79b9);
+
+        for _ in 0..100 {
+            let mut set = CuboidSet::new();
+            let mut expected = HashSet::new();
+
+            for _ in 0..6 {
+                let min = [(); 3].map(|_| random.range(-4, 4));
+                let cuboid = Cuboid::new(min, min.map(|value| value + random.range(0, 6)));
+
+                match random.range(0, 3) {
+                    0 | 1 => {
+                        set.insert(cuboid);
+                        expected.extend(cuboid.points());
+                    },
+                    _ => {
+                        set.remove(cuboid);
+                        cuboid.points().for_each(|point| { expected.remove(&point); });
+                    }
+                }
+            }
+
+            let points: Vec<_> = set.iter().flat_map(|cuboid| cuboid.points()).collect();
+            assert_eq!(points.len(), expected.len(), "cuboids should be disjoint");
+            assert_eq!(points.into_iter().collect::<HashSet<_>>(), expected);
+            assert_eq!(set.volume() as usize, expected.len());
+            assert!(Cuboid::new([-5; 3], [10; 3]).points().all(|point| set.contains(point) == expected.contains(&point)));
+        }
+    }
+}
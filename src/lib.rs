@@ -53,21 +53,26 @@
 //! 
 //! Start by implementing [`Solution<DAY_01>`] for your solutions struct; at minimum, you need to provide 
 //! type definitions for [`Input`](Solution::Input) and [`Output`](Solution::Output), 
-//! as well as an implementation of [`parse`](Solution::parse).
+//! as well as an implementation of [`try_parse`](Solution::try_parse).
 //! ``` ignore
 //! impl Solution<DAY_01> for Solutions {
 //!     type Input<'i> = Vec<u64>;
 //!     type Output = u64;
 //! 
-//!     fn parse(puzzle: &str) -> Self::Input<'_> {
-//!         puzzle
+//!     fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+//!         Ok(puzzle
 //!             .lines()
 //!             .map(str::parse::<u64>())
 //!             .map(Result::unwrap)
-//!             .collect::<Vec<_>>() 
+//!             .collect::<Vec<_>>())
 //!     }
 //! }
 //! ```
+//! Parsers that can fail should return a [`ParseError`] rather than panicking (see below.)
+//! 
+//! If you're upgrading from an earlier version, where `parse` was the method to implement, rename it to `try_parse`
+//! and wrap its result in `Ok`. The panicking [`parse`](SolutionExt::parse) is still available for use in your own code,
+//! but can no longer be implemented.
 //! At this point, the solution is technically ready to be run. You can use the [`solve_through`] macro to execute
 //! all solutions up to a certain day, like so:
 //! ``` ignore
@@ -85,10 +90,10 @@
 //! Part 2: unimplemented
 //! 
 //! --- BENCH (RELEASE) ---
-//! Parsing: 20 ns
-//! Part 1: 20 ns
-//! Part 2: 20 ns
-//! Total: 60 ns
+//! Parsing: 20.00 ns  ███░░░░░░░  33.3%
+//! Part 1:  20.00 ns  ███░░░░░░░  33.3%
+//! Part 2:  20.00 ns  ███░░░░░░░  33.3%
+//! Total:   60.00 ns
 //! ```
 //! It looks like the actual solution logic is unimplemented! Fortunately, that's easy to fix - we just implement
 //! the [`part_one`](Solution::part_one) and [`part_two`](Solution::part_two) methods.
@@ -97,12 +102,12 @@
 //!     type Input<'i> = Vec<u64>;
 //!     type Output = u64;
 //! 
-//!     fn parse(puzzle: &str) -> Self::Input<'_> {
-//!         puzzle
+//!     fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+//!         Ok(puzzle
 //!             .lines()
 //!             .map(str::parse::<u64>())
 //!             .map(Result::unwrap)
-//!             .collect::<Vec<_>>() 
+//!             .collect::<Vec<_>>())
 //!     }
 //! 
 //!     fn part_one(input: &Self::Input<'_>) -> Self::Output {
@@ -129,10 +134,10 @@
 //! Part 2: 95843
 //! 
 //! --- BENCH (RELEASE) ---
-//! Parsing: 7.223 µs
-//! Part 1: 73.838 µs
-//! Part 2: 81.042 µs
-//! Total: 162.244 µs
+//! Parsing:   7.22 µs  ░░░░░░░░░░   4.5%
+//! Part 1:   73.84 µs  █████░░░░░  45.5%
+//! Part 2:   81.04 µs  █████░░░░░  50.0%
+//! Total:   162.24 µs
 //! ```
 //! And that's it - you've implemented a solution!
 //! 
@@ -161,27 +166,301 @@
 //! ```
 //! This expands into a new module with a test function for each part of the solution, and can be run normally via `cargo test`.
 //! 
+//! Some puzzles come with several examples. To check all of them, override [`Test::examples`], which returns pairs of inputs and expected results
+//! (and defaults to the single case above):
+//! ``` ignore
+//! impl Test<DAY_01> for Solutions {
+//!     fn examples(part: bool) -> Vec<(String, Self::Output)> {
+//!         vec![
+//!             (Self::load_test(DAY_01, part), <Self as Test<DAY_01>>::expected(part)),
+//!             (String::from("1000\n\n2000"), 2_000)
+//!         ]
+//!     }
+//! }
+//! ```
+//! 
+//! ## Single-Part Problems
+//! The final day of Advent of Code only has one part. Rather than leaving part two [`Unimplemented`](Answer::Unimplemented) forever,
+//! `lib_aoc` treats it as [`Absent`](Answer::Absent) - it is never executed, isn't benchmarked, and counts as complete.
+//! 
+//! This is controlled by the [`HAS_PART_TWO`](Solution::HAS_PART_TWO) constant, which can be overriden on any [`Solution`]:
+//! ``` ignore
+//! // Day 25 is single-part by default, but any other day can opt in.
+//! impl Solution<DAY_12> for Solutions {
+//!     const HAS_PART_TWO: bool = false;
+//!     /* ... */
+//! }
+//! ```
+//! 
+//! ## Running from the Command Line
+//! If you'd rather pick what to run at runtime, the [`runner`] macro generates a [`Runner`](prelude::Runner) that reads the program's arguments:
+//! ``` ignore
+//! fn main() {
+//!     runner!(Solutions, 25).run();
+//! }
+//! ```
+//! ``` shell
+//! $ cargo run             # Solve every day.
+//! $ cargo run -- 5        # Solve only day five.
+//! $ cargo run -- progress # Print a calendar of earned stars.
+//! $ cargo run -- watch 5  # Re-run day five whenever its inputs change.
+//! $ cargo run -- test     # Check every day against its examples and verified answers.
+//! $ cargo run -- new 6    # Generate a module and input files for day six.
+//! ```
+//! While iterating on a puzzle, `cargo run -- watch 5` re-runs day five (and checks it against its [`Test`] cases, if implemented) whenever its input files change,
+//! showing how the answers changed since the last run. The runner learns which files to watch from [`Solver::input_path`] and [`Solver::test_path`];
+//! passing `--rebuild` additionally watches `src/` and `Cargo.toml`, rebuilding and restarting the program when they change.
+//!
+//! A buggy solution stuck in an infinite loop would normally hang the runner forever. Passing `--timeout <DURATION>` (e.g. `--timeout 10s`)
+//! runs each solution on a worker thread instead, abandoning parsing or any part that exceeds the limit; the part is shown as timed out,
+//! and the runner moves on to the remaining parts and days. The `test` and `watch` commands apply the same limit to each example.
+//!
+//! When solving more than one day, the runner executes days concurrently on a pool of threads (one per core, or as many as `--jobs` specifies)
+//! and prints their outcomes in order. Since concurrent execution skews benchmark measurements, pass `--serial` when you care about timings.
+//!
+//! Output is colored only when it's going to a terminal, so logs and CI output stay free of escape codes. Setting `NO_COLOR` (or `CLICOLOR=0`)
+//! disables colors, `CLICOLOR_FORCE` enables them, and `--color <auto|always|never>` overrides both. Outside the runner, the same policy is
+//! available as [`ColorChoice`](prelude::ColorChoice), and [`plain_text`](prelude::plain_text) renders any outcome, timing or answer without colors.
+//!
+//! `cargo run -- test` checks each day against its [`Test`] examples, and its full puzzle input against its verified answers (see below),
+//! printing a line per case and failing if any of them fail. For CI, `--junit <PATH>` additionally writes the results as a JUnit XML report
+//! with one test suite per day, including each case's timing and expected versus actual answers.
+//!
+//! `cargo run -- new 6` scaffolds day six: it generates `src/day06.rs` (implementing [`Solution`] and [`Test`] with placeholders),
+//! creates empty input files at the paths given by [`Solver::input_path`] and [`Solver::test_path`],
+//! declares the module in `src/main.rs` and raises the day passed to `runner!` or `solve_through!` (if the new day directly follows it). Existing modules are never overwritten.
+//! The generated module can be customized with `--template <PATH>` or [`Runner::template`](prelude::Runner::template);
+//! `{{day}}`, `{{day:02}}` and `{{const}}` in the template are replaced with `6`, `06` and `DAY_06` respectively.
+//! 
+//! The progress report marks each part of each day as earned, missing, or *regressed*. A part has regressed when its computed answer
+//! no longer matches the verified answer returned by [`Solver::verified`] - implement that method (for instance, by reading
+//! your accepted answers from disk) to catch solutions that break while refactoring. Setting [`Solver::YEAR`] labels the report with the year.
+//!
+//! ## Utilities
+//! Alongside the framework itself, `lib_aoc` includes a few modules of helpers for problems that come up every year.
+//!
+//! Many puzzle inputs are character grids. [`grid::Grid`] parses them into a dense grid of cells, and provides bounds-checked indexing,
+//! neighbour iteration, row, column and diagonal iteration, rotation and searching - its `Display` implementation reproduces the puzzle text,
+//! which is handy for debugging:
+//! ``` ignore
+//! impl Solution<DAY_08> for Solutions {
+//!     type Input<'i> = Grid<u8>;
+//!     type Output = usize;
+//!
+//!     fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+//!         Ok(Grid::parse(puzzle, |c| c as u8 - b'0'))
+//!     }
+//!
+//!     fn part_one(input: &Self::Input<'_>) -> Self::Output {
+//!         input
+//!             .iter()
+//!             .filter(|&(position, height)| input.neighbours4(position).all(|(_, other)| other < height))
+//!             .count()
+//!     }
+//! }
+//! ```
+//!
+//! For simulations that grow without bound, such as cellular automata or falling sand, [`grid::SparseGrid`] stores only the cells
+//! that are set, at any (possibly negative) position. It tracks the bounding box of its cells, can compute the next generation of an automaton
+//! with [`step`](grid::SparseGrid::step), and renders its bounding box as text so that it can be printed from inside a solution.
+//!
+//! Instead of redefining coordinate arithmetic every day, [`geom::Point2`] and [`geom::Point3`] provide points
+//! over any integer type, with arithmetic operators, Manhattan and Chebyshev distances and neighbour iteration. [`geom::Direction`]
+//! covers the eight compass directions and can be turned left or right, while [`geom::Hex`] handles hexagonal grids in axial or cube coordinates.
+//! Points can index a `Grid` or be stored in a `SparseGrid` directly.
+//!
+//! Rather than chains of `split` and `parse().unwrap()`, lines can be matched against a pattern with [`scan!`](macro@scan), which extracts typed fields and
+//! reports where a line stopped matching. Since [`Solution::try_parse`] is fallible, these errors can be returned from it directly - if it fails,
+//! neither part is executed, and the error (including its line and column, when using [`scan_lines`](prelude::scan_lines)) is shown in place of each answer:
+//! ```ignore
+//! impl Solution<DAY_05> for Solutions {
+//!     type Input<'i> = Vec<(usize, usize, usize)>;
+//!     type Output = String;
+//!
+//!     fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+//!         scan_lines(puzzle, |line| scan!("move {} from {} to {}", line => usize, usize, usize))
+//!     }
+//! }
+//! ```
+//!
+//! For inputs that don't need a pattern at all, [`ints`](prelude::ints) extracts every integer from some text, [`blocks`](prelude::blocks) splits it into
+//! groups of lines separated by blank lines, [`key_value_pairs`](prelude::key_value_pairs) extracts `key:value` fields and [`digit_grid`](prelude::digit_grid)
+//! parses a grid of single digits. They work on both `&str` and `&[u8]`, and return iterators rather than collecting into vectors:
+//! ```ignore
+//! fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+//!     Ok(blocks(puzzle)
+//!         .map(|elf| ints::<u64>(elf).sum())
+//!         .collect())
+//! }
+//! ```
+//!
+//! The [`search`] module provides breadth-first, depth-first, Dijkstra, A* and bidirectional searches, which all take a closure
+//! producing the successors of a node rather than an explicit graph - so a node can be a grid position, a [`geom::Point2`] or an entire puzzle state.
+//! Searches return the cost of the path they found along with its nodes, and [`search::count_shortest_paths`] and
+//! [`search::reachable`] cover the common variations of counting routes and flood-filling.
+//!
+//! Puzzles that ask for the state of a simulation after a billion steps usually hide a loop. [`cycle::find`] steps through states until one repeats,
+//! and [`cycle::state_at`] uses it to skip straight to any step. When a value such as a tower's height keeps growing while the rest of
+//! the state repeats, [`cycle::extrapolate`] compares states by a key and predicts the value from its growth per loop:
+//! ```ignore
+//! fn part_two(input: &Self::Input<'_>) -> Self::Output {
+//!     cycle::extrapolate(Tower::new(input), 1_000_000_000_000, Tower::drop_rock, Tower::surface, Tower::height)
+//! }
+//! ```
+//!
+//! The [`math`] module collects the number theory that part twos lean on - the Chinese remainder theorem, GCD and LCM (including over whole lists),
+//! modular exponentiation and inverses, and integer roots - for every primitive integer type, without overflowing in intermediate steps.
+//! It also provides combinations, permutations and cartesian products which yield arrays of references, so that they don't allocate per item.
+//!
+//! When a puzzle deals in ranges too large to store value by value, [`IntervalSet`](interval::IntervalSet) stores a set of integers as sorted, disjoint
+//! intervals, with union, intersection and difference. [`CuboidSet`](interval::CuboidSet) does the same for points in any number of dimensions,
+//! splitting [`Cuboid`](interval::Cuboid)s apart as they are removed:
+//! ```ignore
+//! fn part_two(input: &Self::Input<'_>) -> Self::Output {
+//!     let mut reactor = CuboidSet::new();
+//!     for &(on, cuboid) in input {
+//!         match on {
+//!             true => reactor.insert(cuboid),
+//!             false => reactor.remove(cuboid)
+//!         }
+//!     }
+//!     reactor.volume()
+//! }
+//! ```
+//!
+//! Recursive solutions usually need a cache, which is awkward to thread through every call. Wrapping a function in [`memoize`] caches
+//! its results in a thread-local keyed on its arguments, optionally evicting old entries once it reaches a `capacity`. When a function needs
+//! to borrow its input, pass a [`Memo`] around explicitly instead. Either way, each cache's hits and misses are shown beneath the timings:
+//! ```ignore
+//! memoize! {
+//!     fn arrangements(springs: Vec<u8>, groups: Vec<usize>) -> u64 {
+//!         // Recursive calls to `arrangements` share the cache.
+//!     }
+//! }
+//! ```
+//!
 //! ## Notes on Benchmarking
 //! `lib_aoc` provides basic benchmarking of solution implementations via [`std::time::Instant`]. While the
 //! measurements it provides are good approximations, a crate like [`criterion`](https://docs.rs/criterion/latest/criterion/)
 //! is a better choice if you want a more rigorous solution.
 //! 
 //! Also note that execution clock is started *after* your [`Solver::load`] implementation returns, 
-//! immediately before [`Solution::parse`] is invoked. This means the time spent loading the puzzle input is not considered
+//! immediately before [`Solution::try_parse`] is invoked. This means the time spent loading the puzzle input is not considered
 //! by the benchmark.
+//!
+//! If you'd like a finer-grained breakdown, the [`span`] macro can be used inside `try_parse`, `part_one` and `part_two` to time named sub-phases.
+//! Spans can be nested, and are displayed as an indented tree beneath the phase they were created in:
+//! ``` ignore
+//! fn part_one(input: &Self::Input<'_>) -> Self::Output {
+//!     let graph = span!("build graph" => { Graph::new(input) });
+//!     // Times everything until the end of the scope.
+//!     span!("search");
+//!     graph.shortest_path()
+//! }
+//! ```
+//! ``` shell
+//! --- BENCH (RELEASE) ---
+//! Parsing:        7.22 µs  ░░░░░░░░░░   4.5%
+//! Part 1:        73.84 µs  █████░░░░░  45.5%
+//!   build graph: 21.41 µs  █░░░░░░░░░  13.2%
+//!   search:      52.10 µs  ███░░░░░░░  32.1%
+//! ```
 //! 
+//! When optimizing a solution, the [`Runner`](prelude::Runner) can record timings to a baseline file and compare later runs against it:
+//! ``` shell
+//! $ cargo run --release -- 5 --save-baseline
+//! $ cargo run --release -- 5
+//! --- BENCH (RELEASE) ---
+//! Parsing:  7.22 µs  ░░░░░░░░░░   4.7% (-1.2%)
+//! Part 1:  64.68 µs  ████░░░░░░  42.3% (-12.4%)
+//! ```
+//! Changes smaller than the noise threshold (`--noise`, 5% by default) are dimmed, while larger ones are colored green or red.
+//! Passing `--max-regression <PERCENT>` makes the runner exit with an error if any phase slows down by more than that amount.
+//!
+//! Each phase is shown alongside a bar of its share of the total time. Durations are displayed with two decimal places in the most fitting unit;
+//! pass `--precision <N>` or `--unit <ns|us|ms|s>` to the runner to change that, or use [`DurationFormat`](prelude::DurationFormat) to do so from code:
+//! ``` ignore
+//! DurationFormat::new()
+//!     .precision(1)
+//!     .unit(TimeUnit::Millis)
+//!     .apply();
+//! ```
+//!
+//! Timings from debug builds say little about real performance, so the runner warns when benchmarking one. `--debug-policy refuse`
+//! additionally refuses to save baselines from debug builds, while `--debug-policy release` re-runs the program through `cargo run --release`.
+//! Each baseline also records the build that produced it ([`BuildInfo`](prelude::BuildInfo): profile, opt-level, `rustc` version, target and CPU features),
+//! and the runner warns when comparing against a baseline recorded by a different build.
+//!
+//! Allocation counts are often the real reason a solution is slow, so `lib_aoc` also ships [`CountingAllocator`](prelude::CountingAllocator),
+//! a global allocator that records heap usage separately for parsing and each part. Install it in your binary crate to
+//! have allocation counts, peak heap usage and freed bytes shown alongside the timings:
+//! ``` ignore
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+//!
 //! ## Additional Customization Options
-//! By overriding the [`Solver::display`] and [`Solver::finalize`] methods, it's possible to define custom behavior
-//! that is invoked once a solution finishes executing in a non-test context.
-//! 
-//! [`display`](Solver::display) has a default implementation that pretty-prints the solution outcome,
-//! while [`finalize`](Solver::finalize) defaults to a no-op. Both methods take a shared reference to an [`Outcome<impl Display>`].
+//! The [`Runner`](prelude::Runner) renders outcomes through a [`Reporter`](prelude::Reporter), which receives callbacks as a run starts, as each day
+//! and part finishes, and as the run ends. Several reporters are built in and can be picked at runtime with `--format`:
+//! `pretty` (the default), `plain` (no colors), `json`, `markdown`, `table` and `quiet`. You can also implement your own and register it under a name:
+//! ``` ignore
+//! struct Summary { solved: usize }
+//!
+//! impl Reporter for Summary {
+//!     fn day_finished(&mut self, outcome: &Outcome<String>) {
+//!         self.solved += outcome.is_complete() as usize;
+//!     }
+//!
+//!     fn run_end(&mut self) -> Result<(), String> {
+//!         println!("{} days complete!", self.solved);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! fn main() {
+//!     runner!(Solutions, 25)
+//!         .reporter("summary", || Box::new(Summary { solved: 0 }))
+//!         .run();
+//! }
+//! ```
+//!
+//! The `table` format renders a single benchmark table for the whole run, linking each day to its puzzle (when [`Solver::YEAR`] is set)
+//! and showing the stars earned alongside each phase's timing. Pass `--readme` to splice it into an existing file between marker comments
+//! instead of printing it, which is handy for keeping a repository's README up to date after running every day:
+//! ``` text
+//! cargo run --release -- --format table --readme README.md
+//! ```
+//! The file must contain the `<!-- lib_aoc:begin -->` and `<!-- lib_aoc:end -->` markers; everything between them is replaced.
 //! 
+//! Additionally, by overriding the [`Solver::finalize`] method, it's possible to define custom behavior
+//! that is invoked once a solution finishes executing in a non-test context. It defaults to a no-op, and takes a shared reference to an [`Outcome<impl Display>`].
+//!
 //! Want to add some awesome extra behavior like submitting your solution to AoC right from the command line? You can do that here!
 
+mod baseline;
+mod build_info;
+mod check;
+mod cli;
+mod color;
+pub mod cycle;
+mod duration;
+mod extract;
+pub mod geom;
+pub mod grid;
+pub mod interval;
 mod macros;
+pub mod math;
+mod memo;
+mod memory;
 mod outcome;
+mod progress;
+mod report;
+mod runner;
+mod scaffold;
+mod scan;
+pub mod search;
 mod timer;
+mod watch;
 
 mod constants {
     pub const DAY_01: u8 = 1;
@@ -215,23 +494,66 @@ mod constants {
 
 /// Library prelude; glob-import to bring all important items into scope.
 pub mod prelude {
-    pub use crate::{solve, solve_through, solution_array, derive_tests};
-    pub use crate::outcome::{Outcome, Timings};
-    pub use crate::{Solution, Solver, Test};
+    pub use crate::{solve, solve_through, solution_array, derive_tests, runner, scan, memoize};
+    pub use crate::{span, Span};
+    pub use crate::outcome::{Answer, Delta, Outcome, Phase, Timings};
+    pub use crate::memo::{Caches, CacheStats, Memo};
+    pub use crate::memory::{Allocations, AllocStats, CountingAllocator};
+    pub use crate::build_info::BuildInfo;
+    pub use crate::check::{TestCase, Verdict};
+    pub use crate::color::{ColorChoice, plain_text};
+    pub use crate::duration::{DurationFormat, FormattedDuration, TimeUnit};
+    pub use crate::extract::{FromDigits, Text, blocks, digit_grid, digits, ints, key_value_pairs};
+    pub use crate::geom::{Direction, FlatDirection, Hex, Point2, Point3, PointyDirection};
+    pub use crate::grid::{Grid, SparseGrid};
+    pub use crate::interval::{Cuboid, CuboidSet, IntervalSet};
+    pub use crate::progress::{Progress, Star};
+    pub use crate::runner::{Runner, ReporterFn};
+    pub use crate::scan::{ParseError, scan_lines};
+    pub use crate::report::{
+        Reporter,
+        JsonReporter,
+        MarkdownReporter,
+        PlainReporter,
+        PrettyReporter,
+        QuietReporter,
+        TableReporter,
+        splice,
+        TABLE_BEGIN,
+        TABLE_END
+    };
+    pub use crate::{Solution, SolutionExt, Solver, Test};
     pub use crate::constants::*;
 }
 
 // This re-export is necessary for the solve_through! macro to work.
 #[doc(hidden)]
 pub use seq_macro::seq;
+// Likewise, this re-export is necessary for the runner! macro to work.
+#[doc(hidden)]
+pub use runner::Entry;
+#[doc(hidden)]
+pub use outcome::Step;
+#[doc(hidden)]
+pub use check::{execute_test, Probe, ProbeFallback, ProbeTest};
+// Likewise, this re-export is necessary for the scan! macro to work.
+#[doc(hidden)]
+pub use scan::Scanner;
 
 use std::{
+    cell::Cell,
     fmt::{Display, Debug},
-    panic::{self, UnwindSafe, RefUnwindSafe}
+    panic::{self, UnwindSafe, RefUnwindSafe},
+    path::PathBuf,
+    sync::Once
 };
 
-use outcome::Outcome;
-use timer::Timer;
+use outcome::{Answer, Outcome};
+use progress::Progress;
+use scan::ParseError;
+
+pub use timer::Span;
+pub use memo::Memo;
 
 /// Implements the solution to a single Advent of Code problem.
 /// 
@@ -248,8 +570,20 @@ pub trait Solution<const DAY: u8> : Solver {
     /// The type representing the puzzle's solution.
     type Output: Display;
 
-    /// Parse textual puzzle input into a value of type [`Input`](Solution::Input).
-    fn parse(puzzle: &str) -> Self::Input<'_>;
+    /// Whether or not the problem has a second part.
+    /// 
+    /// Defaults to `true` for every day except the last, which traditionally only has
+    /// one part. A part that does not exist is never executed, and is treated as complete 
+    /// (rather than unimplemented) wherever the outcome is displayed or summarized.
+    const HAS_PART_TWO: bool = DAY != 25;
+
+    /// Parse textual puzzle input into a value of type [`Input`](Solution::Input), or describe why it is invalid.
+    /// 
+    /// If parsing fails, neither part is executed and the error is reported as each part's [answer](Answer::Invalid).
+    /// Parsers that can't fail simply wrap their result in `Ok`.
+    /// 
+    /// A panicking wrapper is available as [`SolutionExt::parse`].
+    fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError>;
 
     /// Compute the solution to part one of the problem.
     fn part_one(input: &Self::Input<'_>) -> Self::Output {
@@ -261,72 +595,186 @@ pub trait Solution<const DAY: u8> : Solver {
         panic::panic_any(Unimplemented {})
     }
 
-    /// Execute the solution from start to finish. This method
-    /// handles wiring everything together and should not be overriden.
+    /// Execute the solution from start to finish, then display and finalize the outcome.
+    /// This method handles wiring everything together and should not be overriden.
     fn run() -> Outcome<Self::Output> {
+        let outcome = Self::execute();
+        #[allow(deprecated)]
+        Self::display(&outcome);
+        Self::finalize(&outcome);
+        outcome
+    }
+
+    /// Execute the solution from start to finish without displaying or finalizing the outcome.
+    /// This method should not be overriden.
+    fn execute() -> Outcome<Self::Output> {
         let puzzle = Self::load(DAY);
-        let mut timer = Timer::new();
+        let mut outcome = Outcome::new(DAY);
 
-        let input = Self::parse(&puzzle);
-        timer.mark("Parsing");
+        execute_steps::<Self, DAY>(&puzzle, [true, true], &mut |step| outcome.record(step));
+        outcome
+    }
+}
 
-        let part_one = catch_unimplemented(|| Self::part_one(&input));
-        timer.mark("Part 1");
+/// Methods available on every [`Solution`], which can't be overridden.
+pub trait SolutionExt<const DAY: u8> : Solution<DAY> {
+    /// Parse textual puzzle input, panicking if it is invalid.
+    /// 
+    /// A convenience wrapper around [`try_parse`](Solution::try_parse) for use outside of `lib_aoc` (e.g. in your own tests.)
+    fn parse(puzzle: &str) -> Self::Input<'_>;
+}
 
-        let part_two = catch_unimplemented(|| Self::part_two(&input));
-        timer.mark("Part 2");
-        timer.mark_total("Total");
+impl<S, const DAY: u8> SolutionExt<DAY> for S where
+    S: Solution<DAY> + ?Sized
+{
+    fn parse(puzzle: &str) -> Self::Input<'_> {
+        Self::try_parse(puzzle).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// Parse the puzzle input, then execute the requested parts of a solution
+/// (indexed by [`PART_ONE`](constants::PART_ONE) and [`PART_TWO`](constants::PART_TWO)), 
+/// passing each completed phase to the sink as it finishes.
+#[doc(hidden)]
+pub fn execute_steps<S, const DAY: u8>(puzzle: &str, parts: [bool; 2], sink: &mut dyn FnMut(Step<S::Output>)) where
+    S: Solution<DAY> + ?Sized
+{
+    let profiling = memory::installed();
+
+    let (((input, heap), caches), phase) = timer::measure(timer::PARSING, || {
+        memo::collect(|| memory::profile(|| S::try_parse(puzzle)))
+    });
+    sink(Step::Parsed { phase, heap: profiling.then_some(heap), caches });
 
-        let outcome = Outcome {
-            part_one,
-            part_two,
-            timings: timer.into(),
-            day: DAY
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            // Neither part can run without input; parts that don't exist are still absent.
+            for part in [constants::PART_ONE, constants::PART_TWO] {
+                if parts[part as usize] {
+                    let answer = match part == constants::PART_TWO && !S::HAS_PART_TWO {
+                        true => Answer::Absent,
+                        false => Answer::Invalid(err.to_string())
+                    };
+
+                    sink(Step::Part { part, answer, phase: None, heap: None, caches: Vec::new() });
+                }
+            }
+            return;
+        }
+    };
+
+    if parts[constants::PART_ONE as usize] {
+        let ((result, caches), phase) = timer::measure(timer::PART_ONE, || {
+            memo::collect(|| catch_unimplemented(|| memory::profile(|| S::part_one(&input))))
+        });
+        let (answer, heap) = result.unzip();
+
+        sink(Step::Part {
+            part: constants::PART_ONE,
+            answer: answer.into(),
+            phase: Some(phase),
+            heap: heap.filter(|_| profiling),
+            caches
+        });
+    }
+
+    if parts[constants::PART_TWO as usize] {
+        let step = match S::HAS_PART_TWO {
+            true => {
+                let ((result, caches), phase) = timer::measure(timer::PART_TWO, || {
+                    memo::collect(|| catch_unimplemented(|| memory::profile(|| S::part_two(&input))))
+                });
+                let (answer, heap) = result.unzip();
+
+                Step::Part {
+                    part: constants::PART_TWO,
+                    answer: answer.into(),
+                    phase: Some(phase),
+                    heap: heap.filter(|_| profiling),
+                    caches
+                }
+            },
+            false => Step::Part {
+                part: constants::PART_TWO,
+                answer: Answer::Absent,
+                phase: None,
+                heap: None,
+                caches: Vec::new()
+            }
         };
 
-        Self::display(&outcome);
-        Self::finalize(&outcome);
-        outcome
+        sink(step);
     }
 }
 
 /// Marker struct used to indicate panics triggered by unimplemented solutions.
 struct Unimplemented {}
 
+thread_local! {
+    // Set while panics on this thread are being captured rather than reported.
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn install_quiet_hook() {
+    // Install a custom panic hook that surpresses output *only* for
+    // panics generated by unimplemented solutions (indicated by a payload
+    // of the marker type) or on threads that are capturing panics.
+    //
+    // This is only done once (rather than around every call) as solutions may
+    // be executing concurrently on multiple threads.
+    static QUIET_HOOK: Once = Once::new();
+    QUIET_HOOK.call_once(|| {
+        let noisy_hook = panic::take_hook();
+        panic::set_hook(
+            Box::new(move |panic| {
+                let unimplemented = panic.payload().is::<Unimplemented>();
+                
+                if !unimplemented && !SILENCED.get() {
+                    noisy_hook(panic)
+                }
+            })
+        );
+    });
+}
+
 fn catch_unimplemented<T, F>(operation: F) -> Option<T> where 
-    T: Display,
     F: Fn() -> T + UnwindSafe
 {   
-    // Install a custom panic hook that surpresses output *only* for
-    // panics generated by unimplemented solutions, indicated by a payload
-    // of the unit type.
-    let noisy_hook = panic::take_hook();
-    panic::set_hook(
-        Box::new(move |panic| {
-            match panic.payload().downcast_ref::<Unimplemented>()
-            {
-                Some(_) => (),
-                None => noisy_hook(panic)
-            }
-        })
-    );
+    install_quiet_hook();
 
-    let outcome = match panic::catch_unwind(operation) {
+    match panic::catch_unwind(operation) {
         Ok(result) => Some(result),
         Err(panic) => {
             match panic.downcast_ref::<Unimplemented>()
             {
                 Some(_) => None,
-                None => {
-                    let _ = panic::take_hook();
-                    panic::resume_unwind(panic);
-                }
+                None => panic::resume_unwind(panic)
             }
         }
-    };
+    }
+}
+
+/// Like [`panic::catch_unwind`], but without reporting the panic; 
+/// instead, its message is returned as an error.
+fn catch_quietly<T, F>(operation: F) -> Result<T, String> where
+    F: FnOnce() -> T + UnwindSafe
+{
+    install_quiet_hook();
+
+    let silenced = SILENCED.replace(true);
+    let result = panic::catch_unwind(operation);
+    SILENCED.set(silenced);
 
-    let _ = panic::take_hook();
-    outcome
+    result.map_err(|panic| {
+        if let Some(message) = panic.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = panic.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "panicked with a non-string payload".to_string()
+        }
+    })
 }
 
 /// Interface for testing Advent of Code puzzle solutions.
@@ -341,6 +789,15 @@ pub trait Test<const DAY: u8> : Solution<DAY> {
     fn expected(part: bool) -> Self::Output {
         panic!("Expected inputs not provided.")
     }
+
+    /// Provides every example for the specified part, as pairs of inputs and expected results.
+    /// 
+    /// The default implementation returns the single official test case, as given by
+    /// [`load_test`](Solver::load_test) and [`expected`](Test::expected). Override it
+    /// if a puzzle comes with several examples worth checking.
+    fn examples(part: bool) -> Vec<(String, Self::Output)> {
+        vec![(Self::load_test(DAY, part), Self::expected(part))]
+    }
 }
 
 /// Interface for running Advent of Code puzzle solutions.
@@ -348,6 +805,9 @@ pub trait Test<const DAY: u8> : Solution<DAY> {
 /// See [the getting started guide](crate) for more information.
 #[allow(unused_variables)]
 pub trait Solver {
+    /// The year of Advent of Code being solved, if known.
+    const YEAR: Option<u16> = None;
+
     /// Load the full puzzle input for the specified day.
     fn load(day: u8) -> String;
 
@@ -360,6 +820,30 @@ pub trait Solver {
         panic!("Test loading has not been implemented.")
     }
 
+    /// Load the verified answer (i.e. one accepted by Advent of Code) for the specified day and part, if known.
+    /// 
+    /// Verified answers are compared against computed ones by the [`Progress`] report 
+    /// to detect regressions. The default implementation of this method returns `None`.
+    fn verified(day: u8, part: bool) -> Option<String> {
+        None
+    }
+
+    /// The path to the full puzzle input for the specified day, if it is loaded from disk.
+    /// 
+    /// Used by the [`Runner`](prelude::Runner)'s watch mode to detect changes to the input.
+    /// The default implementation of this method returns `None`.
+    fn input_path(day: u8) -> Option<PathBuf> {
+        None
+    }
+
+    /// The path to the test puzzle input for the specified day and part, if it is loaded from disk.
+    /// 
+    /// Used by the [`Runner`](prelude::Runner)'s watch mode to detect changes to the input.
+    /// The default implementation of this method returns `None`.
+    fn test_path(day: u8, part: bool) -> Option<PathBuf> {
+        None
+    }
+
     /// Callback executed after puzzle completion.
     /// 
     /// The default implementation of this method is a no-op;
@@ -371,6 +855,10 @@ pub trait Solver {
     /// Callback executed after puzzle completion to print the outcome.
     /// 
     /// The default implementation of this method pretty-prints the outcome.
+    /// 
+    /// This method is only used by [`Solution::run`] (and therefore the [`solve`] family of macros); 
+    /// the [`Runner`](prelude::Runner) uses a [`Reporter`](prelude::Reporter) instead.
+    #[deprecated(since = "0.9.0", note = "implement `Reporter` and register it with `Runner::reporter` instead")]
     fn display(outcome: &Outcome<impl Display>) {
         print!("{outcome}")
     }
@@ -392,8 +880,8 @@ pub trait Solver {
 ///     type Input<'i> = usize;
 ///     type Output = Split<usize, String>;
 ///     
-///     fn parse(puzzle: &str) -> Self::Input<'_> {
-///         puzzle.parse::<usize>().unwrap()
+///     fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+///         Ok(puzzle.parse::<usize>().unwrap())
 ///     }
 /// 
 ///     fn part_one(input: &Self::Input<'_>) -> Self::Output {
@@ -15,7 +15,7 @@ macro_rules! solve {
 /// In order, the parameters are:
 /// - Your solution type.
 /// - The day to solve through. Must be an integer literal due to macro
-/// limitations.
+///   limitations.
 /// 
 /// Trying to solve through a range with unimplemented solutions will result
 /// in a compilation error.
@@ -74,21 +74,229 @@ macro_rules! derive_tests {
 
             #[test]
             fn part_one() {
-                let expected = <$sols as ::lib_aoc::Test<$day>>::expected(false);
-                let input = <$sols as ::lib_aoc::Solver>::load_test($day, PART_ONE);
-                let parsed = <$sols as ::lib_aoc::Solution<$day>>::parse(&input);
-                let outcome = <$sols as ::lib_aoc::Solution<$day>>::part_one(&parsed);
-                assert_eq!(outcome, expected);
+                for (i, (input, expected)) in <$sols as ::lib_aoc::Test<$day>>::examples(PART_ONE).into_iter().enumerate() {
+                    let parsed = <$sols as ::lib_aoc::Solution<$day>>::try_parse(&input)
+                        .unwrap_or_else(|err| panic!("example {}: invalid input ({err})", i + 1));
+                    let outcome = <$sols as ::lib_aoc::Solution<$day>>::part_one(&parsed);
+                    assert_eq!(outcome, expected, "example {}", i + 1);
+                }
             }
 
             #[test]
             fn part_two() {
-                let expected = <$sols as ::lib_aoc::Test<$day>>::expected(true);
-                let input = <$sols as ::lib_aoc::Solver>::load_test($day, PART_TWO);
-                let parsed = <$sols as ::lib_aoc::Solution<$day>>::parse(&input);
-                let outcome = <$sols as ::lib_aoc::Solution<$day>>::part_two(&parsed);
-                assert_eq!(outcome, expected);
+                // Nothing to test if the problem doesn't have a second part.
+                if !<$sols as ::lib_aoc::Solution<$day>>::HAS_PART_TWO {
+                    return;
+                }
+
+                for (i, (input, expected)) in <$sols as ::lib_aoc::Test<$day>>::examples(PART_TWO).into_iter().enumerate() {
+                    let parsed = <$sols as ::lib_aoc::Solution<$day>>::try_parse(&input)
+                        .unwrap_or_else(|err| panic!("example {}: invalid input ({err})", i + 1));
+                    let outcome = <$sols as ::lib_aoc::Solution<$day>>::part_two(&parsed);
+                    assert_eq!(outcome, expected, "example {}", i + 1);
+                }
+            }
+        }
+    };
+}
+
+/// Generates a [`Runner`](crate::prelude::Runner) for all days in the range `1..=N`,
+/// which can then be driven from the command line.
+/// 
+/// In order, the parameters are:
+/// - Your solution type.
+/// - The day to solve through. Must be an integer literal due to macro
+///   limitations.
+/// 
+/// Example usage:
+/// ``` ignore
+/// fn main() {
+///     // `cargo run` solves every day, `cargo run -- 5` solves day five,
+///     // `cargo run -- watch 5` re-runs day five whenever its inputs change,
+///     // and `cargo run -- progress` prints a progress report.
+///     runner!(Solutions, 25).run();
+/// }
+/// ```
+#[macro_export]
+macro_rules! runner {
+    ($sols:ty, $up_to:literal) => {
+        ::lib_aoc::seq!(N in 1..=$up_to {
+            ::lib_aoc::prelude::Runner::<$sols>::new(vec![
+                #(
+                ::lib_aoc::Entry {
+                    day: N,
+                    steps: |puzzle, parts, sink| {
+                        ::lib_aoc::execute_steps::<$sols, N>(puzzle, parts, &mut |step| sink(step.into_strings()))
+                    },
+                    has_part_two: <$sols as ::lib_aoc::Solution<N>>::HAS_PART_TWO,
+                    test: {
+                        use ::lib_aoc::{ProbeTest as _, ProbeFallback as _};
+                        (&::lib_aoc::Probe::<$sols, N>::new()).test_fn()
+                    }
+                },
+                )*
+            ])
+        })
+    };
+}
+
+
+/// Times a named phase of a solution, recording it into the solution's [`Timings`](crate::prelude::Timings).
+/// 
+/// There are two forms:
+/// - `span!(name)` times from the invocation until the end of the enclosing scope.
+///   Several of these in the same scope nest inside one another rather than following each other;
+///   use the block form to time consecutive phases.
+/// - `span!(name => { ... })` times only the given block, evaluating to its result.
+/// 
+/// The name can be any expression convertible into a `Cow<'static, str>`. Spans can be nested,
+/// and are displayed as an indented tree in the benchmark output; see [`Span`](crate::Span) for details.
+/// 
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// fn part_one(input: &[u64]) -> u64 {
+///     let sorted = span!("sort" => {
+///         let mut sorted = input.to_vec();
+///         sorted.sort_unstable();
+///         sorted
+///     });
+/// 
+///     span!("sum");
+///     sorted.iter().rev().take(3).sum()
+/// }
+/// # assert_eq!(part_one(&[1, 5, 2, 4]), 11);
+/// ```
+#[macro_export]
+macro_rules! span {
+    ($name:expr => $body:block) => {
+        {
+            let _span = $crate::Span::enter($name);
+            $body
+        }
+    };
+    ($name:expr) => {
+        let _span = $crate::Span::enter($name);
+    };
+}
+
+/// Extracts typed fields from a line of puzzle input, according to a pattern.
+/// 
+/// Each `{}` in the pattern is a placeholder for a field, which is parsed using [`FromStr`](std::str::FromStr)
+/// as the corresponding type; everything else must match the line exactly (ignoring trailing whitespace.)
+/// A field extends up to the literal text that follows its placeholder, so placeholders must be separated by some text.
+/// 
+/// Evaluates to a `Result` of the parsed field (or a tuple of fields, if there are several) and a 
+/// [`ParseError`](crate::prelude::ParseError) giving the column at which the line stopped matching. Use 
+/// [`scan_lines`](crate::prelude::scan_lines) to add line numbers.
+/// 
+/// # Panics
+/// If the number of placeholders and types differ, or two placeholders are adjacent.
+/// 
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let (count, from, to) = scan!("move {} from {} to {}", "move 3 from 1 to 2" => u32, usize, usize).unwrap();
+/// assert_eq!((count, from, to), (3, 1, 2));
+/// 
+/// let error = scan!("move {} from {} to {}", "move 3 form 1 to 2" => u32, usize, usize).unwrap_err();
+/// assert_eq!(error.to_string(), "column 7: expected ` from `, found ` form 1 to 2`");
+/// 
+/// let error = scan!("Sensor at x={}", "Sensor at x=-2a" => i64).unwrap_err();
+/// assert_eq!(error.to_string(), "column 13: could not parse `-2a` as i64: invalid digit found in string");
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:literal, $line:expr => $ty:ty $(,)?) => {
+        (|| {
+            let mut scanner = $crate::Scanner::new($pattern, $line);
+            let value = scanner.field::<$ty>()?;
+            scanner.finish().map(|_| value)
+        })()
+    };
+    ($pattern:literal, $line:expr => $($ty:ty),+ $(,)?) => {
+        (|| {
+            let mut scanner = $crate::Scanner::new($pattern, $line);
+            let value = ($(scanner.field::<$ty>()?,)+);
+            scanner.finish().map(|_| value)
+        })()
+    };
+}
+
+/// Defines a function whose results are cached, keyed on its arguments.
+/// 
+/// The cache lives in a thread-local, so recursive calls share it without passing it around. Inside a solution it is
+/// emptied at the end of each part, and its hit and miss counts are shown alongside the benchmark output; elsewhere
+/// (e.g. in tests) it persists for the lifetime of the thread. Prefix the function with `capacity = N;` to evict the oldest
+/// entry whenever the cache holds `N` entries.
+/// 
+/// Arguments must be owned values implementing `Clone`, `Eq` and `Hash`, and the return type must implement `Clone`.
+/// Use a [`Memo`](crate::Memo) directly for functions that borrow their arguments.
+/// 
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// memoize! {
+///     /// Counts the stones that a single stone splits into after blinking `n` times.
+///     fn stones(stone: u64, n: u32) -> u64 {
+///         if n == 0 {
+///             return 1;
+///         }
+/// 
+///         let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+///         match stone {
+///             0 => stones(1, n - 1),
+///             _ if digits % 2 == 0 => {
+///                 let half = 10_u64.pow(digits / 2);
+///                 stones(stone / half, n - 1) + stones(stone % half, n - 1)
+///             },
+///             _ => stones(stone * 2024, n - 1)
+///         }
+///     }
+/// }
+/// 
+/// memoize! {
+///     capacity = 64;
+///     fn fibonacci(n: u64) -> u64 {
+///         match n {
+///             0 | 1 => n,
+///             _ => fibonacci(n - 1) + fibonacci(n - 2)
+///         }
+///     }
+/// }
+/// 
+/// assert_eq!([125, 17].map(|stone| stones(stone, 25)).iter().sum::<u64>(), 55312);
+/// assert_eq!(fibonacci(90), 2_880_067_194_370_816_120);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    (capacity = $capacity:expr; $(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block) => {
+        $crate::memoize!(@define Some($capacity); $(#[$attr])* $vis fn $name($($arg: $ty),*) -> $ret $body);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block) => {
+        $crate::memoize!(@define None; $(#[$attr])* $vis fn $name($($arg: $ty),*) -> $ret $body);
+    };
+    (@define $capacity:expr; $(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            ::std::thread_local! {
+                static CACHE: ::std::cell::RefCell<$crate::Memo<($($ty,)*), $ret>> =
+                    ::std::cell::RefCell::new($crate::Memo::shared(stringify!($name), $capacity, flush));
+            }
+
+            fn flush() {
+                CACHE.with_borrow_mut($crate::Memo::flush);
             }
+
+            let key = ($(::std::clone::Clone::clone(&$arg),)*);
+            if let Some(value) = CACHE.with_borrow_mut(|cache| cache.get(&key)) {
+                return value;
+            }
+
+            // The body is evaluated in a closure, so that it can return early without skipping the cache.
+            let value = (move || -> $ret { $body })();
+            CACHE.with_borrow_mut(|cache| cache.insert(key, ::std::clone::Clone::clone(&value)));
+            value
         }
     };
-}
\ No newline at end of file
+}
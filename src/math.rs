@@ -0,0 +1,480 @@
+//! Number theory and combinatorics that keep turning up in part twos.
+//!
+//! Everything is generic over the primitive integer types (including `i128` and `u128`) through the [`Int`] trait.
+//! Modular arithmetic never overflows, even when the modulus is close to the type's maximum; functions that can produce
+//! results too large for the type have `checked_` variants.
+//!
+//! The combinatorial iterators yield arrays of references rather than vectors, so they don't allocate per item. For
+//! lengths only known at runtime, [`next_permutation`], [`next_combination`] and [`next_product`] advance a slice in place.
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::math;
+//! // Buses depart every 7, 13 and 59 minutes, with offsets of 0, 1 and 4 minutes.
+//! let (time, period) = math::crt([(0i64, 7), (-1, 13), (-4, 59)]).unwrap();
+//! assert_eq!((time, period), (350, 5369));
+//!
+//! let pairs: Vec<_> = math::combinations::<_, 2>(&['a', 'b', 'c']).collect();
+//! assert_eq!(pairs, [[&'a', &'b'], [&'a', &'c'], [&'b', &'c']]);
+//! ```
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, Div, Mul, Neg, Rem, Shl, Sub}
+};
+
+/// A primitive integer type.
+pub trait Int: Copy + Ord + Hash + Debug + Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+    + Shl<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// The number of bits in the type.
+    const BITS: u32;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The least non-negative remainder of dividing by `modulus`.
+    fn rem_euclid(self, modulus: Self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($int:ty),*) => {
+        $(
+            impl Int for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const BITS: u32 = <$int>::BITS;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$int>::rem_euclid(self, modulus)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn abs<T: Int>(value: T) -> T {
+    match value < T::ZERO {
+        true => T::ZERO - value,
+        false => value
+    }
+}
+
+/// The greatest common divisor of two numbers, which is never negative.
+pub fn gcd<T: Int>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    abs(a)
+}
+
+/// The least common multiple of two numbers, or `None` if it doesn't fit in the type.
+pub fn checked_lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (abs(a) / gcd(a, b)).checked_mul(abs(b))
+}
+
+/// The least common multiple of two numbers, which is never negative.
+///
+/// # Panics
+/// If the result doesn't fit in the type.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("LCM should fit in the type")
+}
+
+/// The greatest common divisor of every number, or zero if there are none.
+pub fn gcd_all<T: Int>(values: impl IntoIterator<Item = T>) -> T {
+    values
+        .into_iter()
+        .fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of every number, or `None` if it doesn't fit in the type.
+pub fn checked_lcm_all<T: Int>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, checked_lcm)
+}
+
+/// The least common multiple of every number, or one if there are none.
+///
+/// # Panics
+/// If the result doesn't fit in the type.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// assert_eq!(math::lcm_all([4u64, 6, 10]), 60);
+/// assert_eq!(math::checked_lcm_all([251u8, 2]), None);
+/// assert_eq!(math::gcd_all([-12i32, 18, 30]), 6);
+/// ```
+pub fn lcm_all<T: Int>(values: impl IntoIterator<Item = T>) -> T {
+    checked_lcm_all(values).expect("LCM should fit in the type")
+}
+
+/// The extended Euclidean algorithm: returns `(g, x, y)` such that `g` is the GCD of `a` and `b`, and `a * x + b * y = g`.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// let (g, x, y) = math::egcd(240i64, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, g);
+/// ```
+pub fn egcd<T: Int + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    match old_r < T::ZERO {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y)
+    }
+}
+
+fn check_modulus<T: Int>(modulus: T) {
+    assert!(modulus > T::ZERO, "Modulus should be positive, but was {modulus}");
+}
+
+/// Adds two numbers already reduced modulo `modulus`, without overflowing.
+fn add_reduced<T: Int>(a: T, b: T, modulus: T) -> T {
+    match a >= modulus - b {
+        true => a - (modulus - b),
+        false => a + b
+    }
+}
+
+/// Subtracts two numbers already reduced modulo `modulus`, without overflowing.
+fn sub_reduced<T: Int>(a: T, b: T, modulus: T) -> T {
+    match a >= b {
+        true => a - b,
+        false => a + (modulus - b)
+    }
+}
+
+/// Multiplies two numbers modulo `modulus`, without overflowing.
+///
+/// # Panics
+/// If the modulus is not positive.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// assert_eq!(math::mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+/// assert_eq!(math::mul_mod(-3i8, 5, 7), 6);
+/// ```
+pub fn mul_mod<T: Int>(a: T, b: T, modulus: T) -> T {
+    check_modulus(modulus);
+
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Fall back to multiplying by doubling and adding, which only needs values below the modulus.
+    let two = T::ONE + T::ONE;
+    let mut product = T::ZERO;
+
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            product = add_reduced(product, a, modulus);
+        }
+
+        a = add_reduced(a, a, modulus);
+        b = b / two;
+    }
+
+    product
+}
+
+/// Raises `base` to the power of `exponent` modulo `modulus`, without overflowing.
+///
+/// # Panics
+/// If the exponent is negative, or the modulus is not positive.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// assert_eq!(math::modpow(7u64, 20201227 - 2, 20201227) * 7 % 20201227, 1);
+/// assert_eq!(math::modpow(2u128, 127, u128::MAX), 1 << 127);
+/// assert_eq!(math::modpow(2u128, 128, u128::MAX), 1);
+/// ```
+pub fn modpow<T: Int>(base: T, mut exponent: T, modulus: T) -> T {
+    check_modulus(modulus);
+    assert!(exponent >= T::ZERO, "Exponent should not be negative, but was {exponent}");
+
+    let two = T::ONE + T::ONE;
+    let mut base = base.rem_euclid(modulus);
+    let mut result = T::ONE % modulus;
+
+    while exponent > T::ZERO {
+        if exponent % two == T::ONE {
+            result = mul_mod(result, base, modulus);
+        }
+
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / two;
+    }
+
+    result
+}
+
+/// The modular multiplicative inverse of `a`, or `None` if `a` and `modulus` are not coprime.
+///
+/// # Panics
+/// If the modulus is not positive.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// assert_eq!(math::modinv(3u8, 250), Some(167));
+/// assert_eq!(math::modinv(-3i32, 7), Some(2));
+/// assert_eq!(math::modinv(4u32, 6), None);
+/// ```
+pub fn modinv<T: Int>(a: T, modulus: T) -> Option<T> {
+    check_modulus(modulus);
+
+    // The extended Euclidean algorithm, keeping the coefficients reduced so that they never go negative.
+    let (mut old_r, mut r) = (a.rem_euclid(modulus), modulus);
+    let (mut old_s, mut s) = (T::ONE % modulus, T::ZERO);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, sub_reduced(old_s, mul_mod(quotient, s, modulus), modulus));
+    }
+
+    (old_r == T::ONE).then_some(old_s)
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` using the Chinese remainder theorem.
+///
+/// Returns the smallest non-negative solution, along with the least common multiple of the moduli (which every other
+/// solution differs by). The moduli don't need to be coprime; returns `None` if there is no solution,
+/// or if the LCM of the moduli doesn't fit in the type.
+///
+/// # Panics
+/// If any modulus is not positive.
+pub fn crt<T: Int>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(residue, modulus), (other_residue, other_modulus)| {
+            check_modulus(other_modulus);
+
+            let divisor = gcd(modulus, other_modulus);
+            let difference = sub_reduced(
+                other_residue.rem_euclid(other_modulus),
+                residue % other_modulus,
+                other_modulus
+            );
+
+            if difference % divisor != T::ZERO {
+                return None;
+            }
+
+            let combined = (modulus / divisor).checked_mul(other_modulus)?;
+            let reduced = other_modulus / divisor;
+
+            // Find how many steps of the current modulus reach the other residue.
+            let inverse = modinv(modulus / divisor, reduced)?;
+            let steps = mul_mod(difference / divisor, inverse, reduced);
+
+            Some((residue + modulus * steps, combined))
+        })
+}
+
+/// The largest integer whose `k`th power is at most `n`.
+///
+/// # Panics
+/// If `n` is negative or `k` is zero.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// assert_eq!(math::iroot(1_000_000u32, 3), 100);
+/// assert_eq!(math::iroot(999_999u32, 3), 99);
+/// assert_eq!(math::iroot(u128::MAX, 2), u64::MAX as u128);
+/// ```
+pub fn iroot<T: Int>(n: T, k: u32) -> T {
+    assert!(n >= T::ZERO, "Cannot take the root of a negative number ({n})");
+    assert!(k > 0, "Cannot take the zeroth root");
+
+    let fits = |candidate: T| {
+        (1..k)
+            .try_fold(candidate, |power, _| power.checked_mul(candidate))
+            .is_some_and(|power| power <= n)
+    };
+
+    // Decide each bit of the root from the highest down; no root can have more than `BITS / k` bits.
+    // Setting the sign bit of a signed type makes the candidate negative, so it is never kept.
+    (0..T::BITS.div_ceil(k))
+        .rev()
+        .fold(T::ZERO, |root, bit| {
+            let candidate = root + (T::ONE << bit);
+            match candidate > root && fits(candidate) {
+                true => candidate,
+                false => root
+            }
+        })
+}
+
+/// The largest integer whose square is at most `n`.
+///
+/// # Panics
+/// If `n` is negative.
+pub fn isqrt<T: Int>(n: T) -> T {
+    iroot(n, 2)
+}
+
+/// Rearranges the items into the next lexicographically greater permutation, returning `false` (and sorting them)
+/// if they were already in the last permutation.
+///
+/// Starting from sorted items, this visits every distinct permutation exactly once, even if some items are equal.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// let mut items = [1, 1, 2];
+/// let mut seen = vec![items];
+/// while math::next_permutation(&mut items) {
+///     seen.push(items);
+/// }
+///
+/// assert_eq!(seen, [[1, 1, 2], [1, 2, 1], [2, 1, 1]]);
+/// assert_eq!(items, [1, 1, 2]);
+/// ```
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        items.reverse();
+        return false;
+    };
+
+    let successor = items
+        .iter()
+        .rposition(|item| *item > items[pivot])
+        .expect("An item after the pivot should be greater than it");
+
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+    true
+}
+
+/// Advances strictly increasing indices into `0..n` to the next combination in lexicographic order,
+/// returning `false` (and resetting them to `0, 1, 2...`) if they were already at the last.
+///
+/// # Panics
+/// If there are more than `n` indices.
+pub fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    let Some(position) = (0..k).rev().find(|&i| indices[i] < n - k + i) else {
+        indices
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, index)| *index = i);
+        return false;
+    };
+
+    indices[position] += 1;
+    for i in position + 1..k {
+        indices[i] = indices[i - 1] + 1;
+    }
+    true
+}
+
+/// Advances indices into `0..n` like an odometer, returning `false` (and resetting them to zero)
+/// if they were already all at `n - 1`.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// // Try every combination of `+` and `*` between four numbers.
+/// let mut operators = [0; 3];
+/// let mut count = 1;
+/// while math::next_product(&mut operators, 2) {
+///     count += 1;
+/// }
+///
+/// assert_eq!(count, 8);
+/// ```
+pub fn next_product(indices: &mut [usize], n: usize) -> bool {
+    for index in indices.iter_mut().rev() {
+        *index += 1;
+        if *index < n {
+            return true;
+        }
+        *index = 0;
+    }
+
+    false
+}
+
+/// Yields each selection of indices from `initial`, using `advance` to move to the next.
+fn select<'a, T, const K: usize>(
+    items: &'a [T],
+    initial: Option<[usize; K]>,
+    advance: impl Fn(&mut [usize; K]) -> bool + 'a
+) -> impl Iterator<Item = [&'a T; K]> + 'a {
+    let mut indices = initial;
+
+    std::iter::from_fn(move || {
+        let current = indices?;
+        let mut next = current;
+        indices = advance(&mut next).then_some(next);
+
+        Some(current.map(|index| &items[index]))
+    })
+}
+
+/// Iterates over every way of choosing `K` of the items, ignoring order, in lexicographic order of their positions.
+pub fn combinations<T, const K: usize>(items: &[T]) -> impl Iterator<Item = [&T; K]> + '_ {
+    let n = items.len();
+    let initial = (K <= n).then(|| std::array::from_fn(|i| i));
+
+    select(items, initial, move |indices| next_combination(indices, n))
+}
+
+/// Iterates over every ordered arrangement of `K` of the items.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::math;
+/// let routes: Vec<_> = math::permutations::<_, 2>(&["A", "B", "C"]).collect();
+///
+/// assert_eq!(routes.len(), 6);
+/// assert!(routes.contains(&[&"C", &"A"]));
+/// ```
+pub fn permutations<T, const K: usize>(items: &[T]) -> impl Iterator<Item = [&T; K]> + '_ {
+    let n = items.len();
+    let initial = (K <= n).then(|| std::array::from_fn(|i| i));
+
+    // Visit every ordering of each combination; when the orderings run out, the combination is sorted again.
+    select(items, initial, move |indices| next_permutation(indices) || next_combination(indices, n))
+}
+
+/// Iterates over the cartesian product of the items with themselves `K` times, i.e. every sequence of `K` items.
+pub fn product<T, const K: usize>(items: &[T]) -> impl Iterator<Item = [&T; K]> + '_ {
+    let n = items.len();
+    let initial = (K == 0 || n > 0).then_some([0; K]);
+
+    select(items, initial, move |indices| next_product(indices, n))
+}
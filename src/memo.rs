@@ -0,0 +1,334 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt::{Display, Write},
+    hash::Hash
+};
+use colored::Colorize;
+
+use crate::timer;
+
+thread_local! {
+    // Cache statistics for the phase currently executing on this thread.
+    static COLLECTION: RefCell<Collection> = const {
+        RefCell::new(Collection {
+            generation: 0,
+            active: false,
+            stats: Vec::new(),
+            shared: Vec::new()
+        })
+    };
+}
+
+struct Collection {
+    /// Incremented whenever a phase begins, so that shared caches know when to register themselves again.
+    generation: u64,
+    active: bool,
+    stats: Vec<CacheStats>,
+    /// Flushes each shared cache used during the phase.
+    shared: Vec<fn()>
+}
+
+/// Executes the provided closure, returning its result and statistics for every cache used by it.
+///
+/// Shared caches (created by the [`memoize`](crate::memoize) macro) are flushed afterwards, so that
+/// they don't outlive the phase they were used in.
+pub(crate) fn collect<T>(operation: impl FnOnce() -> T) -> (T, Vec<CacheStats>) {
+    COLLECTION.with_borrow_mut(|collection| {
+        collection.generation += 1;
+        collection.active = true;
+        collection.stats.clear();
+        collection.shared.clear();
+    });
+
+    let value = operation();
+
+    let shared = COLLECTION.with_borrow_mut(|collection| std::mem::take(&mut collection.shared));
+    for flush in shared {
+        flush();
+    }
+
+    let stats = COLLECTION.with_borrow_mut(|collection| {
+        collection.active = false;
+        std::mem::take(&mut collection.stats)
+    });
+
+    (value, stats)
+}
+
+/// Adds a cache's statistics to the current phase, combining them with any existing cache of the same name.
+fn report(stats: &CacheStats) {
+    if stats.hits == 0 && stats.misses == 0 {
+        return;
+    }
+
+    // Shared caches are dropped as their thread exits, possibly after the collection itself.
+    let _ = COLLECTION.try_with(|collection| {
+        let mut collection = collection.borrow_mut();
+        if !collection.active {
+            return;
+        }
+
+        match collection.stats.iter_mut().find(|existing| existing.name == stats.name) {
+            Some(existing) => {
+                existing.hits += stats.hits;
+                existing.misses += stats.misses;
+                existing.evictions += stats.evictions;
+                existing.peak = existing.peak.max(stats.peak);
+            },
+            None => collection.stats.push(stats.clone())
+        }
+    });
+}
+
+/// A cache of the results of a function, keyed on its arguments.
+///
+/// Recursive functions can take the cache as an argument and call [`get_or_compute`](Memo::get_or_compute),
+/// which passes it back to them on a miss. If the cache is [bounded](Memo::bounded), the oldest entry is evicted
+/// whenever it is full.
+///
+/// When a cache is dropped inside a solution, its hit and miss counts are recorded alongside the benchmark
+/// output for that part (see [`Caches`]). The [`memoize`](crate::memoize) macro is usually more convenient
+/// for functions whose arguments are all owned values.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// // Counts the ways to make the target from any number of each coin.
+/// fn ways(memo: &mut Memo<(usize, u32), u64>, coins: &[u32], index: usize, target: u32) -> u64 {
+///     memo.get_or_compute((index, target), |memo| match coins.get(index) {
+///         _ if target == 0 => 1,
+///         None => 0,
+///         Some(&coin) if coin > target => ways(memo, coins, index + 1, target),
+///         Some(&coin) => ways(memo, coins, index, target - coin) + ways(memo, coins, index + 1, target)
+///     })
+/// }
+///
+/// let mut memo = Memo::new("ways");
+/// assert_eq!(ways(&mut memo, &[1, 2, 5, 10, 20, 50, 100, 200], 0, 200), 73682);
+/// assert_eq!(memo.stats().misses, memo.len() as u64);
+///
+/// let mut bounded = Memo::bounded("bounded", 2);
+/// for n in [1, 2, 3, 1] {
+///     bounded.get_or_compute(n, |_| n * n);
+/// }
+/// assert_eq!((bounded.stats().misses, bounded.stats().evictions), (4, 2));
+/// ```
+pub struct Memo<K, V> {
+    entries: HashMap<K, V>,
+    /// Keys in order of insertion, if the cache is bounded.
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: CacheStats,
+    /// For caches created by `memoize!`, the function that flushes the cache and the last phase it was used in.
+    shared: Option<(fn(), u64)>
+}
+
+impl<K, V> Memo<K, V> where
+    K: Clone + Eq + Hash,
+    V: Clone
+{
+    /// Creates an empty cache of unlimited size. The name identifies it in the benchmark output.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            stats: CacheStats::new(name.into()),
+            shared: None
+        }
+    }
+
+    /// Creates an empty cache that holds at most `capacity` entries.
+    ///
+    /// # Panics
+    /// If `capacity` is zero.
+    pub fn bounded(name: impl Into<Cow<'static, str>>, capacity: usize) -> Self {
+        assert!(capacity > 0, "Cache capacity should be non-zero.");
+
+        let mut memo = Self::new(name);
+        memo.capacity = Some(capacity);
+        memo
+    }
+
+    /// Creates a cache for the `memoize` macro, which lives in a thread-local and is flushed
+    /// at the end of each phase it is used in.
+    #[doc(hidden)]
+    pub fn shared(name: &'static str, capacity: Option<usize>, flush: fn()) -> Self {
+        let mut memo = match capacity {
+            Some(capacity) => Self::bounded(name, capacity),
+            None => Self::new(name)
+        };
+        memo.shared = Some((flush, 0));
+        memo
+    }
+
+    /// Returns the cached value for the key, recording a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        if let Some((flush, generation)) = &mut self.shared {
+            COLLECTION.with_borrow_mut(|collection| {
+                if collection.active && *generation != collection.generation {
+                    collection.shared.push(*flush);
+                    *generation = collection.generation;
+                }
+            });
+        }
+
+        let value = self.entries.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1
+        }
+
+        value
+    }
+
+    /// Caches the value for the key, evicting the oldest entry if the cache is full.
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(capacity) = self.capacity {
+            if let Some(existing) = self.entries.get_mut(&key) {
+                *existing = value;
+                return;
+            }
+
+            if self.entries.len() >= capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+            }
+
+            self.order.push_back(key.clone());
+        }
+
+        self.entries.insert(key, value);
+        self.stats.peak = self.stats.peak.max(self.entries.len());
+    }
+
+    /// Returns the cached value for the key, or computes and caches it on a miss.
+    ///
+    /// `compute` receives the cache, so that it can be passed on to recursive calls.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+
+        let value = compute(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// The number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry from the cache, without resetting its statistics.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// The cache's statistics so far.
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    /// Records the cache's statistics in the current phase, then empties it and resets them.
+    #[doc(hidden)]
+    pub fn flush(&mut self) {
+        report(&self.stats);
+        self.clear();
+        self.stats = CacheStats::new(self.stats.name.clone());
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        report(&self.stats);
+    }
+}
+
+/// Represents the usage of a single [`Memo`] cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheStats {
+    pub name: Cow<'static, str>,
+    /// The number of lookups that found a cached value.
+    pub hits: u64,
+    /// The number of lookups that didn't.
+    pub misses: u64,
+    /// The number of entries evicted from a bounded cache.
+    pub evictions: u64,
+    /// The largest number of entries held at once.
+    pub peak: usize
+}
+
+impl CacheStats {
+    fn new(name: Cow<'static, str>) -> Self {
+        Self {
+            name,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            peak: 0
+        }
+    }
+
+    /// The fraction of lookups that found a cached value.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Represents the caches used during the execution of a solution.
+///
+/// Only available if the solution used a [`Memo`] (or a function defined with [`memoize`](crate::memoize).)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Caches {
+    pub parsing: Vec<CacheStats>,
+    pub part_one: Vec<CacheStats>,
+    pub part_two: Vec<CacheStats>
+}
+
+impl Display for Caches {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        writeln!(output, "{}:", "Caches".bold())?;
+
+        for (phase, caches) in [
+            (timer::PARSING, &self.parsing),
+            (timer::PART_ONE, &self.part_one),
+            (timer::PART_TWO, &self.part_two)
+        ] {
+            for stats in caches {
+                write!(
+                    output,
+                    "  {} {}: {} hits, {} misses ({:.1}% hit rate), {} peak entries",
+                    phase.bold(),
+                    stats.name.bold(),
+                    stats.hits,
+                    stats.misses,
+                    stats.hit_rate() * 100.0,
+                    stats.peak
+                )?;
+
+                if stats.evictions > 0 {
+                    write!(output, ", {} evicted", stats.evictions)?;
+                }
+                writeln!(output)?;
+            }
+        }
+
+        write!(f, "{}", output.trim())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{Display, Write},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed}
+};
+use colored::Colorize;
+
+use crate::timer;
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Heap usage by lib_aoc's own bookkeeping on this thread, which is subtracted from profiles.
+    static UNTRACKED: Cell<Untracked> = const { Cell::new(Untracked { active: false, count: 0, allocated: 0, deallocated: 0 }) };
+}
+
+#[derive(Clone, Copy)]
+struct Untracked {
+    active: bool,
+    count: usize,
+    allocated: usize,
+    deallocated: usize
+}
+
+/// A global allocator that counts heap usage, enabling `lib_aoc` to profile
+/// the allocations made by each phase of a solution.
+/// 
+/// Allocation is delegated to [`System`]; install it in your binary crate like so:
+/// ``` no_run
+/// use lib_aoc::prelude::*;
+/// 
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// # fn main() {}
+/// ```
+/// Once installed, every [`Outcome`](crate::prelude::Outcome) will contain [`Allocations`] data.
+/// 
+/// Note that the counters are process-wide, so allocations made by other threads 
+/// while a solution is executing will be attributed to it.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new
+    }
+}
+
+fn record_alloc(size: usize) {
+    COUNT.fetch_add(1, Relaxed);
+    ALLOCATED.fetch_add(size, Relaxed);
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+
+    let _ = UNTRACKED.try_with(|untracked| {
+        let mut usage = untracked.get();
+        if usage.active {
+            usage.count += 1;
+            usage.allocated += size;
+            untracked.set(usage);
+        }
+    });
+}
+
+fn record_dealloc(size: usize) {
+    DEALLOCATED.fetch_add(size, Relaxed);
+    CURRENT.fetch_sub(size, Relaxed);
+
+    let _ = UNTRACKED.try_with(|untracked| {
+        let mut usage = untracked.get();
+        if usage.active {
+            usage.deallocated += size;
+            untracked.set(usage);
+        }
+    });
+}
+
+/// Executes the provided closure without attributing its heap usage to the phase being profiled on this thread.
+/// 
+/// The global counters still include it (so that they remain consistent), but [`profile`] subtracts it afterwards.
+pub(crate) fn untracked<T>(operation: impl FnOnce() -> T) -> T {
+    let was_active = UNTRACKED.with(|untracked| {
+        let mut usage = untracked.get();
+        let was_active = usage.active;
+        usage.active = true;
+        untracked.set(usage);
+        was_active
+    });
+
+    // Restore the previous state even if the operation panics, so that later allocations are still counted.
+    let _restore = Restore(was_active);
+    operation()
+}
+
+/// Guard that restores whether heap usage on this thread is untracked once dropped.
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let _ = UNTRACKED.try_with(|untracked| {
+            let mut usage = untracked.get();
+            usage.active = self.0;
+            untracked.set(usage);
+        });
+    }
+}
+
+/// Returns `true` if the [`CountingAllocator`] is installed as the global allocator.
+pub(crate) fn installed() -> bool {
+    // Make a throwaway allocation and check whether it was counted.
+    let before = COUNT.load(Relaxed);
+    drop(std::hint::black_box(Box::new(0_u8)));
+    COUNT.load(Relaxed) != before
+}
+
+/// Executes the provided closure, returning its result and the heap usage it incurred.
+pub(crate) fn profile<T>(operation: impl FnOnce() -> T) -> (T, AllocStats) {
+    let count = COUNT.load(Relaxed);
+    let allocated = ALLOCATED.load(Relaxed);
+    let deallocated = DEALLOCATED.load(Relaxed);
+    let current = CURRENT.load(Relaxed);
+    let excluded = UNTRACKED.with(Cell::get);
+    PEAK.store(current, Relaxed);
+
+    let value = operation();
+
+    let untracked = UNTRACKED.with(Cell::get);
+    let untracked_allocated = untracked.allocated - excluded.allocated;
+    let untracked_deallocated = untracked.deallocated - excluded.deallocated;
+
+    // Bookkeeping that is still live (such as recorded spans) inflates the peak, so it is excluded from that too.
+    let stats = AllocStats {
+        count: (COUNT.load(Relaxed) - count).saturating_sub(untracked.count - excluded.count),
+        allocated: (ALLOCATED.load(Relaxed) - allocated).saturating_sub(untracked_allocated),
+        deallocated: (DEALLOCATED.load(Relaxed) - deallocated).saturating_sub(untracked_deallocated),
+        peak: PEAK.load(Relaxed)
+            .saturating_sub(current)
+            .saturating_sub(untracked_allocated.saturating_sub(untracked_deallocated))
+    };
+
+    (value, stats)
+}
+
+/// Represents the heap usage of a single phase of a solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The number of allocations (including reallocations) performed.
+    pub count: usize,
+    /// The total number of bytes allocated.
+    pub allocated: usize,
+    /// The total number of bytes deallocated.
+    pub deallocated: usize,
+    /// The peak number of bytes in use, relative to the start of the phase.
+    pub peak: usize
+}
+
+/// Represents heap profiling data from the execution of a solution.
+/// 
+/// Only available if the [`CountingAllocator`] is installed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub parsing: AllocStats,
+    /// `None` if part one is unimplemented.
+    pub part_one: Option<AllocStats>,
+    /// `None` if part two is unimplemented or doesn't exist.
+    pub part_two: Option<AllocStats>
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        writeln!(output, "{}:", "Heap".bold())?;
+
+        let mut write_stats = |stats: &AllocStats, name: &str| {
+            writeln!(
+                output,
+                "  {}: {} allocs, {} peak, {} freed",
+                name.bold(),
+                stats.count,
+                format_bytes(stats.peak),
+                format_bytes(stats.deallocated)
+            )
+        };
+
+        write_stats(&self.parsing, timer::PARSING)?;
+        if let Some(part_one) = &self.part_one {
+            write_stats(part_one, timer::PART_ONE)?;
+        }
+        if let Some(part_two) = &self.part_two {
+            write_stats(part_two, timer::PART_TWO)?;
+        }
+
+        write!(f, "{}", output.trim())?;
+        Ok(())
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
@@ -1,23 +1,109 @@
 use std::{
+    borrow::Cow,
     fmt::{Display, Write},
     time::Duration
 };
 use colored::{Colorize, ColoredString};
 
-use crate::Timer;
+use crate::{
+    build_info::BuildInfo,
+    constants::{PART_ONE, PART_TWO},
+    duration::DurationFormat,
+    memo::{Caches, CacheStats},
+    memory::{Allocations, AllocStats},
+    report::plain_duration,
+    timer
+};
 
 /// Represents the final product of a [`Solution`](crate::Solution).
 pub struct Outcome<T: Display> {
-    /// The computed answer to part one, if any.
-    pub part_one: Option<T>,
-    /// The computer answer to part two, if any.
-    pub part_two: Option<T>,
+    /// The computed answer to part one.
+    pub part_one: Answer<T>,
+    /// The computed answer to part two.
+    pub part_two: Answer<T>,
     /// Benchmark timing data.
     pub timings: Timings,
+    /// Heap profiling data, if the [`CountingAllocator`](crate::prelude::CountingAllocator) is installed.
+    pub allocations: Option<Allocations>,
+    /// Cache statistics, if the solution used a [`Memo`](crate::Memo) or [`memoize`](crate::memoize) function.
+    pub caches: Option<Caches>,
     /// The day of the source [`Solution`](crate::Solution).
     pub day: u8,
 }
 
+impl<T: Display> Outcome<T> {
+    /// Creates an empty outcome for the specified day, with both parts unimplemented.
+    pub(crate) fn new(day: u8) -> Self {
+        Self {
+            part_one: Answer::Unimplemented,
+            part_two: Answer::Unimplemented,
+            timings: Timings::default(),
+            allocations: None,
+            caches: None,
+            day
+        }
+    }
+
+    /// Records a completed step of the solution's execution.
+    pub(crate) fn record(&mut self, step: Step<T>) {
+        match step {
+            Step::Parsed { phase, heap, caches } => {
+                self.timings.push(phase);
+                if let Some(heap) = heap {
+                    self.allocations.get_or_insert_with(Allocations::default).parsing = heap;
+                }
+                if !caches.is_empty() {
+                    self.caches.get_or_insert_with(Caches::default).parsing = caches;
+                }
+            },
+            Step::Part { part, answer, phase, heap, caches } => {
+                if let Some(phase) = phase {
+                    self.timings.push(phase);
+                }
+
+                if let Some(heap) = heap {
+                    let allocations = self.allocations.get_or_insert_with(Allocations::default);
+                    match part {
+                        PART_ONE => allocations.part_one = Some(heap),
+                        PART_TWO => allocations.part_two = Some(heap)
+                    }
+                }
+
+                if !caches.is_empty() {
+                    let stats = self.caches.get_or_insert_with(Caches::default);
+                    match part {
+                        PART_ONE => stats.part_one = caches,
+                        PART_TWO => stats.part_two = caches
+                    }
+                }
+
+                match part {
+                    PART_ONE => self.part_one = answer,
+                    PART_TWO => self.part_two = answer
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if every part of the problem that exists has been answered.
+    pub fn is_complete(&self) -> bool {
+        self.part_one.is_complete() && self.part_two.is_complete()
+    }
+
+    /// Converts the computed answers into their textual representation,
+    /// erasing the concrete output type.
+    pub fn into_strings(self) -> Outcome<String> {
+        Outcome {
+            part_one: self.part_one.map(|value| value.to_string()),
+            part_two: self.part_two.map(|value| value.to_string()),
+            timings: self.timings,
+            allocations: self.allocations,
+            caches: self.caches,
+            day: self.day
+        }
+    }
+}
+
 impl<T: Display> Display for Outcome<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f)?;
@@ -25,84 +111,329 @@ impl<T: Display> Display for Outcome<T> {
         writeln!(f, "{}: {}", "Part 1".bold(), format_answer(&self.part_one))?;
         writeln!(f, "{}: {}", "Part 2".bold(), format_answer(&self.part_two))?;
 
-        let opt_target = match cfg!(debug_assertions) {
+        let opt_target = match self.timings.build.debug {
             true => "(DEBUG)".yellow().bold(),
             false => "(RELEASE)".green().bold()
         };
 
         writeln!(f, "\n--- BENCH {opt_target} ---\n{}", self.timings)?;
 
+        if let Some(allocations) = &self.allocations {
+            writeln!(f, "\n{allocations}")?;
+        }
+
+        if let Some(caches) = &self.caches {
+            writeln!(f, "\n{caches}")?;
+        }
+
         Ok(())
     }
 }
 
-fn format_answer(ans: &Option<impl Display>) -> ColoredString {
+fn format_answer(ans: &Answer<impl Display>) -> ColoredString {
     match ans {
-        Some(answer) => format!("{answer}").green(),
-        None => "unimplemented".red()
+        Answer::Solved(answer) => format!("{answer}").green(),
+        Answer::Unimplemented => "unimplemented".red(),
+        Answer::Absent => "n/a".dimmed(),
+        Answer::TimedOut(limit) => format!("timed out after {}", plain_duration(*limit)).red(),
+        Answer::Invalid(message) => format!("invalid input ({message})").red()
+    }
+}
+
+/// Represents a completed phase of a solution's execution, sent from [`execute_steps`](crate::execute_steps).
+#[doc(hidden)]
+pub enum Step<T> {
+    Parsed {
+        phase: Phase,
+        heap: Option<AllocStats>,
+        caches: Vec<CacheStats>
+    },
+    Part {
+        part: bool,
+        answer: Answer<T>,
+        phase: Option<Phase>,
+        heap: Option<AllocStats>,
+        caches: Vec<CacheStats>
+    }
+}
+
+impl<T: Display> Step<T> {
+    /// Converts the computed answer (if any) into its textual representation.
+    pub fn into_strings(self) -> Step<String> {
+        match self {
+            Step::Parsed { phase, heap, caches } => Step::Parsed { phase, heap, caches },
+            Step::Part { part, answer, phase, heap, caches } => Step::Part {
+                part,
+                answer: answer.map(|value| value.to_string()),
+                phase,
+                heap,
+                caches
+            }
+        }
+    }
+}
+
+/// Represents the result of executing one part of a [`Solution`](crate::Solution).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer<T> {
+    /// The part was solved, producing a value.
+    Solved(T),
+    /// The part has not been implemented yet.
+    Unimplemented,
+    /// The part does not exist, such as the second half of the final day.
+    Absent,
+    /// The part was abandoned after exceeding the time limit.
+    TimedOut(Duration),
+    /// The part could not be executed because the puzzle input failed to parse; holds the error message.
+    Invalid(String)
+}
+
+impl<T> Answer<T> {
+    /// Returns `true` if the part was solved or does not exist.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Answer::Solved(_) | Answer::Absent)
+    }
+
+    /// Returns a reference to the computed value, if any.
+    pub fn solved(&self) -> Option<&T> {
+        match self {
+            Answer::Solved(value) => Some(value),
+            _ => None
+        }
+    }
+
+    /// Maps the computed value (if any) using the provided closure.
+    pub fn map<U>(self, op: impl FnOnce(T) -> U) -> Answer<U> {
+        match self {
+            Answer::Solved(value) => Answer::Solved(op(value)),
+            Answer::Unimplemented => Answer::Unimplemented,
+            Answer::Absent => Answer::Absent,
+            Answer::TimedOut(limit) => Answer::TimedOut(limit),
+            Answer::Invalid(message) => Answer::Invalid(message)
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Answer<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Answer::Solved(value),
+            None => Answer::Unimplemented
+        }
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Solved(value) => write!(f, "{value}"),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+            Answer::Absent => write!(f, "n/a"),
+            Answer::TimedOut(limit) => write!(f, "timed out after {}", plain_duration(*limit)),
+            Answer::Invalid(message) => write!(f, "invalid input ({message})")
+        }
     }
 }
 
 /// Represents benchmarking timing data from the execution of a solution.
+#[derive(Default)]
 pub struct Timings {
-    pub parsing: Duration,
-    pub part_one: Duration,
-    pub part_two: Duration,
-    pub total: Duration
+    /// The top-level phases of execution (parsing and each part), in order.
+    pub phases: Vec<Phase>,
+    pub total: Duration,
+    /// The change in total time relative to a baseline, if any.
+    pub delta: Option<Delta>,
+    /// How the benchmarked code was compiled.
+    pub build: BuildInfo
 }
 
-impl From<Timer> for Timings {
-    fn from(timer: Timer) -> Self {
-        let buffer: Vec<_> = timer
-            .buffer()
+impl Timings {
+    fn push(&mut self, phase: Phase) {
+        self.total += phase.duration;
+        self.phases.push(phase);
+    }
+
+    /// Returns the top-level phase with the specified name, if any.
+    pub fn phase(&self, name: &str) -> Option<&Phase> {
+        self.phases
             .iter()
-            .map(|lap| lap.1)
-            .collect();
+            .find(|phase| phase.name == name)
+    }
 
-        Self {
-            parsing: buffer[0],
-            part_one: buffer[1],
-            part_two: buffer[2],
-            total: buffer[3]
-        }
+    /// Returns the time spent parsing the puzzle input.
+    pub fn parsing(&self) -> Duration {
+        self.phase(timer::PARSING)
+            .map(|phase| phase.duration)
+            .unwrap_or_default()
+    }
+
+    /// Returns the time spent computing part one.
+    pub fn part_one(&self) -> Duration {
+        self.phase(timer::PART_ONE)
+            .map(|phase| phase.duration)
+            .unwrap_or_default()
+    }
+
+    /// Returns the time spent computing part two, or `None` if the problem doesn't have a second part.
+    pub fn part_two(&self) -> Option<Duration> {
+        self.phase(timer::PART_TWO)
+            .map(|phase| phase.duration)
     }
 }
 
 impl Display for Timings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Size the columns to fit the widest label and duration in the tree.
+        let mut columns = Columns {
+            label: "Total".len(),
+            value: 0,
+            format: DurationFormat::current(),
+            total: self.total
+        };
+
+        for phase in &self.phases {
+            columns.fit(phase, 0);
+        }
+        columns.value = columns.value.max(columns.format.split(self.total).0.len());
+        columns.format = columns.format.width(columns.value);
+
         let mut output = String::new();
 
-        let mut write_timing = |time: &Duration, name: &str| {
-            let (time, units) = format_duration(time);
-            writeln!(
-                output,
-                "{}: {time} {units}",
-                name.bold()
-            )
-        };
+        for phase in &self.phases {
+            write_phase(&mut output, phase, 0, &columns)?;
+        }
 
-        write_timing(&self.parsing, "Parsing")?;
-        write_timing(&self.part_one, "Part 1")?;
-        write_timing(&self.part_two, "Part 2")?;
-        write_timing(&self.total, "Total")?;
+        write!(output, "{}", columns.label("Total", 0))?;
+        write!(output, " {}", columns.format.format(self.total))?;
+        write_delta(&mut output, &self.delta)?;
+        writeln!(output)?;
 
-        write!(f, "{}", output.trim())?;
+        write!(f, "{}", output.trim_end())?;
         Ok(())
     }
 }
 
-fn format_duration(value: &Duration) -> (String, ColoredString) {
-    let (time, units): (String, String) = format!("{value:?}")
-        .chars()
-        .partition(|char| char.is_numeric() || char == &'.' || char == &',');
+/// The width of a percentage-of-total bar, in characters.
+const BAR_WIDTH: usize = 10;
+
+/// Column layout for displaying a tree of phases.
+struct Columns {
+    label: usize,
+    value: usize,
+    format: DurationFormat,
+    total: Duration
+}
+
+impl Columns {
+    fn fit(&mut self, phase: &Phase, depth: usize) {
+        self.label = self.label.max(depth * 2 + phase.name.chars().count());
+        self.value = self.value.max(self.format.split(phase.duration).0.len());
+
+        for child in &phase.children {
+            self.fit(child, depth + 1);
+        }
+    }
+
+    /// Renders an indented label, padded so that the durations after it line up.
+    fn label(&self, name: &str, depth: usize) -> String {
+        let padding = self.label - depth * 2 - name.chars().count();
+        format!("{}{}:{}", "  ".repeat(depth), name.bold(), " ".repeat(padding))
+    }
+
+    /// Renders a bar showing what fraction of the total the duration makes up.
+    fn bar(&self, duration: Duration) -> String {
+        let fraction = match self.total.is_zero() {
+            true => 0.0,
+            false => (duration.as_secs_f64() / self.total.as_secs_f64()).min(1.0)
+        };
+        let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+
+        format!(
+            "{}{} {:>5.1}%",
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH - filled).dimmed(),
+            fraction * 100.0
+        )
+    }
+}
+
+fn write_phase(output: &mut String, phase: &Phase, depth: usize, columns: &Columns) -> std::fmt::Result {
+    write!(output, "{}", columns.label(&phase.name, depth))?;
+    write!(output, " {}  {}", columns.format.format(phase.duration), columns.bar(phase.duration))?;
+    write_delta(output, &phase.delta)?;
+    if phase.count > 1 {
+        write!(output, " {}", format!("(x{})", phase.count).dimmed())?;
+    }
+    writeln!(output)?;
 
+    for child in &phase.children {
+        write_phase(output, child, depth + 1, columns)?;
+    }
 
-    let units = match units.as_str() {
-        "ns" => units.cyan(),
-        "µs" => units.green(),
-        "ms" => units.yellow(),
-        _ => units.red()
-    };
+    Ok(())
+}
+
+/// Represents a named phase of a solution's execution, such as parsing or a [`Span`](crate::Span).
+#[derive(Debug, Clone)]
+pub struct Phase {
+    pub name: Cow<'static, str>,
+    /// The total time spent in the phase.
+    pub duration: Duration,
+    /// The number of times the phase was entered.
+    pub count: usize,
+    /// Phases nested inside of this one, in order of first entry.
+    pub children: Vec<Phase>,
+    /// The change in time spent relative to a baseline, if any.
+    pub delta: Option<Delta>
+}
 
-    (time, units)
-}
\ No newline at end of file
+/// Represents the change in a duration relative to a baseline measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+    /// The baseline measurement.
+    pub baseline: Duration,
+    /// The relative change in percent; positive values are slower than the baseline.
+    pub percent: f64,
+    /// Whether or not the change is larger than the noise threshold.
+    pub significant: bool
+}
+
+impl Delta {
+    /// Compares a measurement against a baseline, ignoring changes smaller than `noise` percent.
+    pub fn new(measured: Duration, baseline: Duration, noise: f64) -> Self {
+        let percent = match baseline.is_zero() {
+            true => 0.0,
+            false => (measured.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+        };
+
+        Self {
+            baseline,
+            percent,
+            significant: percent.abs() > noise
+        }
+    }
+
+    /// Returns `true` if the measurement is significantly slower than the baseline.
+    pub fn is_regression(&self) -> bool {
+        self.significant && self.percent > 0.0
+    }
+}
+
+impl Display for Delta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = format!("({:+.1}%)", self.percent);
+        
+        match (self.significant, self.percent > 0.0) {
+            (false, _) => write!(f, "{}", text.dimmed()),
+            (true, true) => write!(f, "{}", text.red()),
+            (true, false) => write!(f, "{}", text.green())
+        }
+    }
+}
+
+fn write_delta(output: &mut String, delta: &Option<Delta>) -> std::fmt::Result {
+    match delta {
+        Some(delta) => write!(output, " {delta}"),
+        None => Ok(())
+    }
+}
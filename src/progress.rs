@@ -0,0 +1,125 @@
+use std::fmt::{Display, Write};
+use colored::Colorize;
+
+use crate::outcome::{Answer, Outcome};
+
+/// Represents the status of a single star (i.e. one part of a day's problem.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    /// The part has been answered, and the answer matches the verified one (if known.)
+    Earned,
+    /// The part has not been answered yet (or timed out.)
+    Missing,
+    /// The part has been answered, but the answer no longer matches the verified one.
+    Regressed
+}
+
+impl Star {
+    /// Determines the status of a star from a computed answer and the verified answer, if known.
+    /// 
+    /// Parts that do not exist are considered earned.
+    pub fn new(answer: &Answer<impl Display>, verified: Option<&str>) -> Self {
+        match (answer, verified) {
+            (Answer::Unimplemented | Answer::TimedOut(_) | Answer::Invalid(_), _) => Star::Missing,
+            (Answer::Solved(value), Some(verified)) if value.to_string() != verified => Star::Regressed,
+            _ => Star::Earned
+        }
+    }
+}
+
+/// Summarizes the stars earned by a set of solutions across the Advent of Code calendar.
+/// 
+/// Generate one using [`Runner::progress`](crate::prelude::Runner::progress).
+pub struct Progress {
+    /// The year of the calendar, if known.
+    pub year: Option<u16>,
+    /// The stars for each day, indexed by `day - 1`. `None` for days without a solution.
+    pub days: [Option<[Star; 2]>; 25]
+}
+
+impl Progress {
+    /// Creates an empty progress report for the specified year.
+    pub fn new(year: Option<u16>) -> Self {
+        Self {
+            year,
+            days: [None; 25]
+        }
+    }
+
+    /// Records the stars earned by an outcome, given the verified answers for each part (if known.)
+    /// Outcomes for days outside of the calendar are ignored.
+    pub fn record(&mut self, outcome: &Outcome<impl Display>, verified: [Option<String>; 2]) {
+        let [one, two] = verified;
+        
+        if let Some(slot) = (outcome.day as usize).checked_sub(1).and_then(|index| self.days.get_mut(index)) {
+            *slot = Some([
+                Star::new(&outcome.part_one, one.as_deref()),
+                Star::new(&outcome.part_two, two.as_deref())
+            ]);
+        }
+    }
+
+    /// Returns the total number of stars earned.
+    pub fn stars(&self) -> usize {
+        self.days
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|star| **star == Star::Earned)
+            .count()
+    }
+
+    /// Returns an iterator over the days with at least one regressed star.
+    pub fn regressions(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days
+            .iter()
+            .zip(1..)
+            .filter(|(stars, _)| stars.is_some_and(|stars| stars.contains(&Star::Regressed)))
+            .map(|(_, day)| day)
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        match self.year {
+            Some(year) => writeln!(f, "--- PROGRESS ({}) ---", year.to_string().bright_cyan().bold())?,
+            None => writeln!(f, "--- PROGRESS ---")?
+        }
+
+        for week in self.days.chunks(5).zip((1..).step_by(5)) {
+            let mut row = String::new();
+
+            for (stars, day) in week.0.iter().zip(week.1..) {
+                let stars = match stars {
+                    Some(stars) => stars
+                        .iter()
+                        .map(|star| match star {
+                            Star::Earned => "*".yellow().bold(),
+                            Star::Missing => ".".dimmed(),
+                            Star::Regressed => "!".red().bold()
+                        })
+                        .fold(String::new(), |acc, star| acc + &star.to_string()),
+                    None => "--".dimmed().to_string()
+                };
+
+                write!(row, "{day:>2} {stars}   ")?;
+            }
+
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        writeln!(f, "\n{}: {}/50", "Stars".bold(), self.stars().to_string().yellow().bold())?;
+
+        let regressions: Vec<_> = self
+            .regressions()
+            .map(|day| day.to_string())
+            .collect();
+
+        if !regressions.is_empty() {
+            writeln!(f, "{}: {}", "Regressed".bold(), regressions.join(", ").red())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::PathBuf,
+    time::Duration
+};
+
+use crate::build_info::BuildInfo;
+use crate::color::plain_text;
+use crate::constants::{PART_ONE, PART_TWO};
+use crate::duration::DurationFormat;
+use crate::outcome::{Answer, Outcome, Phase};
+use crate::progress::Star;
+
+/// Receives events from the [`Runner`](crate::prelude::Runner) as solutions are executed, 
+/// and renders them in some output format.
+/// 
+/// Every callback has a no-op default implementation, so only the events of interest need to be handled. 
+/// Events are delivered as they happen, and in day order: when days are executed concurrently,
+/// the events of later days are held back until every earlier day has finished.
+/// 
+/// Custom reporters can be registered using [`Runner::reporter`](crate::prelude::Runner::reporter),
+/// and selected at runtime alongside the built-in ones using `--format`.
+#[allow(unused_variables)]
+pub trait Reporter {
+    /// Called once before any solutions are executed, with the days that will be run.
+    fn run_start(&mut self, days: &[u8]) {}
+
+    /// Called when a day's solution starts executing.
+    fn day_start(&mut self, day: u8) {}
+
+    /// Called as soon as each part of a day's problem finishes, while the rest of the day may still be executing.
+    fn part_finished(&mut self, day: u8, part: bool, answer: &Answer<String>) {}
+
+    /// Called with a day's complete outcome, after each of its parts have been reported.
+    fn day_finished(&mut self, outcome: &Outcome<String>) {}
+
+    /// Called once after every solution has been executed.
+    /// 
+    /// Returning an error (e.g. if the report couldn't be written) makes the runner fail once the run is over.
+    fn run_end(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// An event from the execution of a single day, which is forwarded to a [`Reporter`].
+pub(crate) enum Event {
+    DayStart(u8),
+    PartFinished(u8, bool, Answer<String>),
+    DayFinished(Box<Outcome<String>>)
+}
+
+impl Event {
+    /// The day that the event belongs to.
+    pub(crate) fn day(&self) -> u8 {
+        match self {
+            Event::DayStart(day) | Event::PartFinished(day, ..) => *day,
+            Event::DayFinished(outcome) => outcome.day
+        }
+    }
+
+    /// Sends the event to the corresponding reporter callback.
+    pub(crate) fn send(&self, reporter: &mut dyn Reporter) {
+        match self {
+            Event::DayStart(day) => reporter.day_start(*day),
+            Event::PartFinished(day, part, answer) => reporter.part_finished(*day, *part, answer),
+            Event::DayFinished(outcome) => reporter.day_finished(outcome)
+        }
+    }
+}
+
+/// The default reporter, which pretty-prints each outcome with colors.
+#[derive(Default)]
+pub struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn day_finished(&mut self, outcome: &Outcome<String>) {
+        print!("{outcome}");
+    }
+}
+
+/// Like [`PrettyReporter`], but without colors.
+#[derive(Default)]
+pub struct PlainReporter;
+
+impl Reporter for PlainReporter {
+    fn day_finished(&mut self, outcome: &Outcome<String>) {
+        print!("{}", plain_text(outcome));
+    }
+}
+
+/// Reports nothing at all.
+#[derive(Default)]
+pub struct QuietReporter;
+
+impl Reporter for QuietReporter {}
+
+/// Prints every outcome as a single JSON document once the run ends.
+/// 
+/// Durations are given in nanoseconds. The document also describes the [build](BuildInfo) that produced the timings.
+#[derive(Default)]
+pub struct JsonReporter {
+    days: Vec<String>
+}
+
+impl Reporter for JsonReporter {
+    fn day_finished(&mut self, outcome: &Outcome<String>) {
+        let mut json = String::new();
+        let _ = write!(
+            json,
+            "{{\"day\":{},\"part_one\":{},\"part_two\":{},\"timings\":{{\"total\":{},\"phases\":{}}}}}",
+            outcome.day,
+            json_answer(&outcome.part_one),
+            json_answer(&outcome.part_two),
+            outcome.timings.total.as_nanos(),
+            json_phases(&outcome.timings.phases)
+        );
+        self.days.push(json);
+    }
+
+    fn run_end(&mut self) -> Result<(), String> {
+        let build: Vec<_> = BuildInfo::CURRENT
+            .fields()
+            .into_iter()
+            .map(|(key, value)| format!("{}:{}", json_string(key), json_string(value)))
+            .collect();
+
+        println!("{{\"build\":{{{}}},\"days\":[{}]}}", build.join(","), self.days.join(","));
+        Ok(())
+    }
+}
+
+fn json_answer(answer: &Answer<String>) -> String {
+    match answer {
+        Answer::Solved(value) => format!("{{\"status\":\"solved\",\"answer\":{}}}", json_string(value)),
+        Answer::Unimplemented => "{\"status\":\"unimplemented\"}".to_string(),
+        Answer::Absent => "{\"status\":\"absent\"}".to_string(),
+        Answer::TimedOut(limit) => format!("{{\"status\":\"timed_out\",\"limit\":{}}}", limit.as_nanos()),
+        Answer::Invalid(message) => format!("{{\"status\":\"invalid\",\"error\":{}}}", json_string(message))
+    }
+}
+
+fn json_phases(phases: &[Phase]) -> String {
+    let phases: Vec<_> = phases
+        .iter()
+        .map(|phase| format!(
+            "{{\"name\":{},\"duration\":{},\"count\":{},\"children\":{}}}",
+            json_string(&phase.name),
+            phase.duration.as_nanos(),
+            phase.count,
+            json_phases(&phase.children)
+        ))
+        .collect();
+
+    format!("[{}]", phases.join(","))
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(json, "\\u{:04x}", char as u32);
+            },
+            char => json.push(char)
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+/// Prints each outcome as a Markdown section, with a table of answers and timings.
+#[derive(Default)]
+pub struct MarkdownReporter;
+
+impl Reporter for MarkdownReporter {
+    fn day_finished(&mut self, outcome: &Outcome<String>) {
+        let timings = &outcome.timings;
+
+        println!("## Day {}\n", outcome.day);
+        println!("| Phase | Answer | Time |");
+        println!("| --- | --- | --- |");
+        println!("| Parsing | | {} |", plain_duration(timings.parsing()));
+        println!("| Part 1 | {} | {} |", markdown_answer(&outcome.part_one), plain_duration(timings.part_one()));
+        
+        if let Some(part_two) = timings.part_two() {
+            println!("| Part 2 | {} | {} |", markdown_answer(&outcome.part_two), plain_duration(part_two));
+        } else {
+            println!("| Part 2 | {} | |", markdown_answer(&outcome.part_two));
+        }
+
+        println!("| **Total** | | **{}** |\n", plain_duration(timings.total));
+    }
+}
+
+pub(crate) fn markdown_answer(answer: &Answer<String>) -> String {
+    let cell = match answer {
+        // Multiline answers can't be displayed in a table cell.
+        Answer::Solved(value) if value.contains('\n') => "*(multiline)*".to_string(),
+        Answer::Solved(value) => code_span(value),
+        other => format!("*{other}*")
+    };
+
+    // Pipes would otherwise end the cell, even inside a code span.
+    cell.replace('|', "\\|")
+}
+
+/// Wraps a value in a code span, using a fence longer than any run of backticks it contains.
+fn code_span(value: &str) -> String {
+    let longest = value
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+
+    let fence = "`".repeat(longest + 1);
+    match longest {
+        0 => format!("{fence}{value}{fence}"),
+        // Backticks at either end would merge with the fence, so they are padded with a space.
+        _ => format!("{fence} {value} {fence}")
+    }
+}
+
+/// Formats a duration like the benchmark output, but without colors or alignment.
+pub(crate) fn plain_duration(duration: Duration) -> String {
+    DurationFormat::current()
+        .width(0)
+        .plain(duration)
+}
+
+/// Renders every outcome into a single Markdown table once the run ends, suitable for publishing in a README.
+/// 
+/// Each row links to the day's puzzle (if the year is known), shows the stars earned and the time spent
+/// in each phase. If a path is provided, the table is spliced into that file between the
+/// [`TABLE_BEGIN`] and [`TABLE_END`] marker comments rather than printed.
+pub struct TableReporter {
+    year: Option<u16>,
+    verified: fn(u8, bool) -> Option<String>,
+    output: Option<PathBuf>,
+    rows: Vec<String>,
+    stars: usize,
+    total: Duration
+}
+
+/// Marks the start of the table when splicing into an existing file.
+pub const TABLE_BEGIN: &str = "<!-- lib_aoc:begin -->";
+/// Marks the end of the table when splicing into an existing file.
+pub const TABLE_END: &str = "<!-- lib_aoc:end -->";
+
+impl TableReporter {
+    /// Creates a new table reporter.
+    /// 
+    /// - `year` is used to link each day to its puzzle.
+    /// - `verified` provides the verified answers used to determine [stars](Star) (typically [`Solver::verified`](crate::Solver::verified).)
+    /// - `output` is the file to splice the table into; if `None`, the table is printed instead.
+    pub fn new(year: Option<u16>, verified: fn(u8, bool) -> Option<String>, output: Option<PathBuf>) -> Self {
+        Self {
+            year,
+            verified,
+            output,
+            rows: Vec::new(),
+            stars: 0,
+            total: Duration::ZERO
+        }
+    }
+
+    /// Renders the table from the outcomes reported so far.
+    pub fn render(&self) -> String {
+        let mut table = String::new();
+        table.push_str("| Day | Stars | Parsing | Part 1 | Part 2 | Total |\n");
+        table.push_str("| :-- | :-- | --: | --: | --: | --: |\n");
+
+        for row in &self.rows {
+            table.push_str(row);
+        }
+
+        let _ = writeln!(
+            table,
+            "| **Total** | **{}** | | | | **{}** |",
+            self.stars,
+            plain_duration(self.total)
+        );
+        table
+    }
+}
+
+impl Reporter for TableReporter {
+    fn day_finished(&mut self, outcome: &Outcome<String>) {
+        let day = match self.year {
+            Some(year) => format!("[{0}](https://adventofcode.com/{year}/day/{0})", outcome.day),
+            None => outcome.day.to_string()
+        };
+
+        let stars: String = [(PART_ONE, &outcome.part_one), (PART_TWO, &outcome.part_two)]
+            .into_iter()
+            .map(|(part, answer)| Star::new(answer, (self.verified)(outcome.day, part).as_deref()))
+            .map(|star| match star {
+                Star::Earned => {
+                    self.stars += 1;
+                    "⭐"
+                },
+                Star::Regressed => "❗",
+                Star::Missing => ""
+            })
+            .collect();
+
+        let timings = &outcome.timings;
+        let part = |answer: &Answer<String>, duration: Option<Duration>| match (answer, duration) {
+            (Answer::Solved(_), Some(duration)) => plain_duration(duration),
+            _ => "-".to_string()
+        };
+
+        self.rows.push(format!(
+            "| {day} | {stars} | {} | {} | {} | {} |\n",
+            plain_duration(timings.parsing()),
+            part(&outcome.part_one, Some(timings.part_one())),
+            part(&outcome.part_two, timings.part_two()),
+            plain_duration(timings.total)
+        ));
+        self.total += timings.total;
+    }
+
+    fn run_end(&mut self) -> Result<(), String> {
+        let table = self.render();
+
+        let Some(path) = &self.output else {
+            print!("{table}");
+            return Ok(());
+        };
+
+        fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|document| {
+                splice(&document, &table).ok_or_else(|| format!("could not find the {TABLE_BEGIN} and {TABLE_END} markers"))
+            })
+            .and_then(|document| fs::write(path, document).map_err(|err| err.to_string()))
+            .map_err(|err| format!("could not update {}: {err}", path.display()))?;
+
+        eprintln!("Updated table in {}", path.display());
+        Ok(())
+    }
+}
+
+/// Replaces the text between the [`TABLE_BEGIN`] and [`TABLE_END`] markers in a document, 
+/// returning `None` if the markers are missing or out of order.
+/// 
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let readme = "# Solutions\n<!-- lib_aoc:begin -->\nold\n<!-- lib_aoc:end -->\n";
+/// let spliced = splice(readme, "new\n").unwrap();
+/// 
+/// assert_eq!(spliced, "# Solutions\n<!-- lib_aoc:begin -->\nnew\n<!-- lib_aoc:end -->\n");
+/// ```
+pub fn splice(document: &str, table: &str) -> Option<String> {
+    let begin = document.find(TABLE_BEGIN)? + TABLE_BEGIN.len();
+    let end = begin + document[begin..].find(TABLE_END)?;
+
+    Some(format!("{}\n{table}{}", &document[..begin], &document[end..]))
+}
@@ -0,0 +1,707 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError}
+    },
+    thread,
+    time::Duration
+};
+use colored::Colorize;
+
+use crate::{Solver, Outcome, Progress};
+use crate::outcome::{Answer, Step};
+use crate::baseline::Baseline;
+use crate::build_info::BuildInfo;
+use crate::check::{self, TestCase};
+use crate::report::{
+    Event,
+    Reporter,
+    JsonReporter,
+    MarkdownReporter,
+    PlainReporter,
+    PrettyReporter,
+    QuietReporter,
+    TableReporter
+};
+use crate::cli::{Command, DebugPolicy, Options, USAGE};
+use crate::scaffold;
+use crate::watch::{self, Snapshot};
+use crate::constants::{PART_ONE, PART_TWO};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Constructs a boxed [`Reporter`].
+pub type ReporterFn = fn() -> Box<dyn Reporter>;
+
+/// Type-erased version of [`execute_steps`](crate::execute_steps) for a specific day.
+type StepsFn = fn(&str, [bool; 2], &mut dyn FnMut(Step<String>));
+type TestFn = fn(bool, Option<Duration>) -> Vec<TestCase>;
+
+/// Type-erased entry point for a single day's solution, generated by the [`runner`](crate::runner) macro.
+#[doc(hidden)]
+pub struct Entry {
+    pub day: u8,
+    pub steps: StepsFn,
+    /// Whether the day has a second part (see [`Solution::HAS_PART_TWO`](crate::Solution::HAS_PART_TWO).)
+    pub has_part_two: bool,
+    /// `None` if the day doesn't implement [`Test`](crate::Test).
+    pub test: Option<TestFn>
+}
+
+/// Command-line driver for a set of solutions.
+/// 
+/// Construct one using the [`runner`](crate::runner) macro, then call [`run`](Runner::run)
+/// to execute the command specified by the program's arguments:
+/// - No arguments solves every day.
+/// - A day number (e.g. `cargo run -- 5`) solves only that day.
+/// - `progress` solves every day silently, then prints a [`Progress`] report.
+/// - `watch <DAY>` solves the specified day and checks it against its [`Test`](crate::Test) cases, then does so
+///   again whenever its input files (as given by [`Solver::input_path`] and [`Solver::test_path`]) change.
+///   With `--rebuild`, changes to `src/` or `Cargo.toml` also trigger a rebuild and restart of the program.
+/// - `test [DAY]` checks every day (or only the specified one) against its [`Test`](crate::Test) examples, and
+///   against its [verified answers](Solver::verified) on the full puzzle input. Pass `--junit <PATH>` to also
+///   write the results as a JUnit XML report; the command fails if any case fails.
+/// - `new <DAY>` generates a module for the day from a [template](Runner::template), creates its empty input files
+///   (as given by [`Solver::input_path`] and [`Solver::test_path`]), declares the module in `src/main.rs` (or `src/lib.rs`) and
+///   raises the day passed to `runner!` or `solve_through!` (if the new day directly follows it). Existing modules are never overwritten.
+/// 
+/// When solving, timings are compared against a baseline file (if one exists) and displayed
+/// alongside their relative change. Pass `--save-baseline` to record the current timings, 
+/// `--noise` to set the threshold below which changes are ignored, and `--max-regression` 
+/// to fail if any phase slows down by more than the given percentage.
+/// 
+/// Benchmarking a debug build prints a warning. Pass `--debug-policy refuse` to also refuse to save baselines 
+/// from debug builds, or `--debug-policy release` to re-run the program in release mode through Cargo instead.
+/// 
+/// Passing `--timeout <DURATION>` (e.g. `10s` or `500ms`) limits how long parsing and each part may take.
+/// Parts that exceed the limit are abandoned and marked as [timed out](Answer::TimedOut), and the runner moves on
+/// to the remaining parts and days. The `test` and `watch` commands apply the same limit to each example.
+/// 
+/// When solving multiple days, they are executed concurrently on a pool of threads (sized with `--jobs`)
+/// and displayed in order. Concurrent execution skews benchmark measurements, so pass `--serial` when timings matter;
+/// heap profiling is only available in serial mode.
+/// 
+/// Outcomes are rendered by a [`Reporter`], chosen using `--format`: one of `pretty` (the default), 
+/// `plain`, `json`, `markdown`, `table`, `quiet`, or the name of a custom reporter registered with [`reporter`](Runner::reporter).
+/// Whether output is colored is controlled by `--color` (see [`ColorChoice`](crate::prelude::ColorChoice)), and durations
+/// are displayed with the precision and unit given by `--precision` and `--unit` (see [`DurationFormat`](crate::prelude::DurationFormat).)
+pub struct Runner<S: Solver> {
+    entries: Vec<Entry>,
+    reporters: Vec<(&'static str, ReporterFn)>,
+    template: &'static str,
+    // Solutions are never instantiated, so this shouldn't affect auto traits.
+    solver: PhantomData<fn() -> S>
+}
+
+impl<S: Solver> Runner<S> {
+    #[doc(hidden)]
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self {
+            entries,
+            reporters: Vec::new(),
+            template: scaffold::DEFAULT_TEMPLATE,
+            solver: PhantomData
+        }
+    }
+
+    /// Registers a custom [`Reporter`], which can then be selected using `--format <NAME>`.
+    /// 
+    /// Custom reporters take precedence over built-in ones with the same name.
+    pub fn reporter(mut self, name: &'static str, factory: ReporterFn) -> Self {
+        self.reporters.push((name, factory));
+        self
+    }
+
+    /// Sets the template used by the `new` command to generate a day's module.
+    /// 
+    /// The placeholders `{{day}}`, `{{day:02}}` and `{{const}}` are replaced with the day number,
+    /// the zero-padded day number and the name of the day's constant (e.g. `5`, `05` and `DAY_05`.)
+    /// A template file passed with `--template` takes precedence.
+    pub fn template(mut self, template: &'static str) -> Self {
+        self.template = template;
+        self
+    }
+
+    fn create_reporter(&self, name: &str, readme: Option<&Path>) -> Result<Box<dyn Reporter>, String> {
+        if readme.is_some() && name != "table" {
+            return Err("'--readme' can only be used with '--format table'.".to_string());
+        }
+
+        if let Some((_, factory)) = self.reporters.iter().find(|(custom, _)| *custom == name) {
+            return Ok(factory());
+        }
+
+        match name {
+            "pretty" => Ok(Box::new(PrettyReporter)),
+            "plain" => Ok(Box::new(PlainReporter)),
+            "json" => Ok(Box::<JsonReporter>::default()),
+            "markdown" => Ok(Box::new(MarkdownReporter)),
+            "quiet" => Ok(Box::new(QuietReporter)),
+            "table" => Ok(Box::new(TableReporter::new(S::YEAR, S::verified, readme.map(Path::to_path_buf)))),
+            _ => Err(format!("unknown format '{name}'."))
+        }
+    }
+
+    /// Parses the program's command-line arguments and executes the requested command.
+    /// 
+    /// Exits the process if the arguments are invalid, or if the command fails
+    /// (e.g. because a phase regressed by more than `--max-regression` percent.)
+    pub fn run(&self) {
+        let options = match Options::parse(std::env::args().skip(1)) {
+            Ok(options) => options,
+            Err(message) => {
+                eprintln!("{} {message}\n\n{USAGE}", "error:".red().bold());
+                process::exit(2);
+            }
+        };
+
+        options.color.apply();
+        options.durations.apply();
+
+        if let Err(message) = self.dispatch(&options) {
+            eprintln!("{} {message}", "error:".red().bold());
+            process::exit(1);
+        }
+    }
+
+    fn dispatch(&self, options: &Options) -> Result<(), String> {
+        match options.command {
+            Command::Solve(day) => self.solve_with(day, options),
+            Command::Progress => {
+                print!("{}", self.progress_with(options));
+                Ok(())
+            },
+            Command::Watch(day) => self.watch(day, options),
+            Command::Test(day) => self.test_with(day, options),
+            Command::New(day) => self.scaffold(day, options)
+        }
+    }
+
+    fn scaffold(&self, day: u8, options: &Options) -> Result<(), String> {
+        let template = match &options.template {
+            Some(path) => fs::read_to_string(path)
+                .map_err(|err| format!("could not read template from {}: {err}", path.display()))?,
+            None => self.template.to_string()
+        };
+
+        let mut inputs: Vec<_> = [S::input_path(day), S::test_path(day, PART_ONE), S::test_path(day, PART_TWO)]
+            .into_iter()
+            .flatten()
+            .collect();
+        inputs.dedup();
+
+        let root = match Path::new("src/main.rs").exists() {
+            true => PathBuf::from("src/main.rs"),
+            false => PathBuf::from("src/lib.rs")
+        };
+
+        let layout = scaffold::Layout {
+            modules: PathBuf::from("src"),
+            root,
+            inputs
+        };
+
+        for action in scaffold::scaffold(day, &template, &layout)? {
+            println!("{action}");
+        }
+
+        if layout.inputs.is_empty() {
+            println!("{}", "No input files were created; implement Solver::input_path and Solver::test_path to have them generated.".dimmed());
+        }
+
+        Ok(())
+    }
+
+    fn watch(&self, day: u8, options: &Options) -> Result<(), String> {
+        let entry = self.entries
+            .iter()
+            .find(|entry| entry.day == day)
+            .ok_or_else(|| format!("there is no solution for day {day}."))?;
+
+        let mut inputs: Vec<_> = [S::input_path(day), S::test_path(day, PART_ONE), S::test_path(day, PART_TWO)]
+            .into_iter()
+            .flatten()
+            .collect();
+        inputs.dedup();
+
+        let sources = match options.rebuild {
+            true => watch::sources(),
+            false => Vec::new()
+        };
+
+        if inputs.is_empty() && sources.is_empty() {
+            return Err("there is nothing to watch; implement Solver::input_path and Solver::test_path, or pass --rebuild.".to_string());
+        }
+
+        let mut previous = None;
+        let mut source_snapshot = Snapshot::take(&sources);
+
+        loop {
+            // Each run gets a fresh reporter, so that reporters which only write at the end of a run
+            // (such as the JSON and table formats) show the latest outcome rather than accumulating them.
+            let mut reporter = self.create_reporter(&options.format, options.readme.as_deref())?;
+            let input_snapshot = Snapshot::take(&inputs);
+            watch::clear_screen();
+            reporter.run_start(&[day]);
+
+            // Keep watching even if the solution panics; the panic hook has already reported it.
+            let execution = panic::catch_unwind(AssertUnwindSafe(|| {
+                self.execute(entry, options.timeout, &mut |event| event.send(reporter.as_mut()))
+            }));
+
+            match execution {
+                Ok(outcome) => {
+                    reporter.day_finished(&outcome);
+                    
+                    if let Some(previous) = &previous {
+                        print!("\n{}", watch::changes(previous, &outcome));
+                    }
+                    previous = Some(outcome);
+                },
+                Err(_) => println!("\n{}", "The solution panicked.".red().bold())
+            }
+
+            if let Err(message) = reporter.run_end() {
+                eprintln!("\n{} {message}", "error:".red().bold());
+            }
+
+            if let Some(test) = entry.test {
+                println!();
+                for case in test(PART_ONE, options.timeout).into_iter().chain(test(PART_TWO, options.timeout)) {
+                    println!("{case}");
+                }
+            }
+
+            println!("\n{}", "Watching for changes...".dimmed());
+
+            loop {
+                thread::sleep(POLL_INTERVAL);
+
+                if options.rebuild && Snapshot::take(&sources) != source_snapshot {
+                    source_snapshot = Snapshot::take(&sources);
+                    println!("{}", "Rebuilding...".dimmed());
+                    
+                    if watch::rebuild() {
+                        watch::restart();
+                    }
+                }
+
+                if Snapshot::take(&inputs) != input_snapshot {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn test_with(&self, day: Option<u8>, options: &Options) -> Result<(), String> {
+        let entries: Vec<_> = self.entries
+            .iter()
+            .filter(|entry| day.is_none_or(|day| day == entry.day))
+            .collect();
+
+        if let (Some(day), true) = (day, entries.is_empty()) {
+            return Err(format!("there is no solution for day {day}."));
+        }
+
+        let mut cases: BTreeMap<u8, Vec<TestCase>> = entries
+            .iter()
+            .filter_map(|entry| Some((entry.day, entry.test?)))
+            .map(|(day, test)| (day, test(PART_ONE, options.timeout).into_iter().chain(test(PART_TWO, options.timeout)).collect()))
+            .collect();
+
+        // Only solve the full puzzle input for days that have something to verify it against.
+        let verifiable: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| [PART_ONE, PART_TWO]
+                .into_iter()
+                .any(|part| S::verified(entry.day, part).is_some()))
+            .collect();
+
+        self.execute_all(&verifiable, options, |event| {
+            let Event::DayFinished(outcome) = event else {
+                return;
+            };
+
+            let verified = [PART_ONE, PART_TWO]
+                .into_iter()
+                .filter_map(|part| check::verify(&outcome, part, S::verified(outcome.day, part)));
+
+            cases
+                .entry(outcome.day)
+                .or_default()
+                .extend(verified);
+        });
+
+        let cases: Vec<_> = cases
+            .into_values()
+            .flatten()
+            .collect();
+
+        for (i, case) in cases.iter().enumerate() {
+            if i == 0 || cases[i - 1].day != case.day {
+                println!("\n--- DAY {} ---", case.day.to_string().bright_cyan().bold());
+            }
+            println!("{case}");
+        }
+
+        let failures = cases
+            .iter()
+            .filter(|case| case.is_failure())
+            .count();
+
+        println!("\n{} cases, {} failed", cases.len(), failures);
+
+        if let Some(path) = &options.junit {
+            fs::write(path, check::junit(&cases))
+                .map_err(|err| format!("could not write JUnit report to {}: {err}", path.display()))?;
+
+            eprintln!("Wrote JUnit report to {}", path.display());
+        }
+
+        match failures {
+            0 => Ok(()),
+            _ => Err(format!("{failures} test case(s) failed."))
+        }
+    }
+
+    fn solve_with(&self, day: Option<u8>, options: &Options) -> Result<(), String> {
+        let entries: Vec<_> = self.entries
+            .iter()
+            .filter(|entry| day.is_none_or(|day| day == entry.day))
+            .collect();
+
+        if let (Some(day), true) = (day, entries.is_empty()) {
+            return Err(format!("there is no solution for day {day}."));
+        }
+
+        if BuildInfo::CURRENT.debug {
+            match options.debug_policy {
+                DebugPolicy::Release => process::exit(run_in_release()?),
+                DebugPolicy::Refuse if options.save_baseline => {
+                    return Err("refusing to save a baseline from a debug build; run with --release.".to_string());
+                },
+                _ => eprintln!(
+                    "{} benchmarking a debug build, so timings are not representative. Run with --release, or pass --debug-policy release.\n",
+                    "warning:".yellow().bold()
+                )
+            }
+        }
+
+        let mut reporter = self.create_reporter(&options.format, options.readme.as_deref())?;
+        let mut baseline = Baseline::load(&options.baseline)
+            .map_err(|err| format!("could not load baseline from {}: {err}", options.baseline.display()))?;
+
+        let changes = baseline.build_changes(&BuildInfo::CURRENT);
+        if !changes.is_empty() {
+            eprintln!(
+                "{} the baseline was recorded by a different build, so comparisons may be misleading:\n  {}\n",
+                "warning:".yellow().bold(),
+                changes.join("\n  ")
+            );
+        }
+
+        let mut regressions = Vec::new();
+        let days: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.day)
+            .collect();
+
+        reporter.run_start(&days);
+        self.execute_all(&entries, options, |event| {
+            let Event::DayFinished(mut outcome) = event else {
+                return event.send(reporter.as_mut());
+            };
+
+            baseline.compare(&mut outcome, options.noise);
+
+            reporter.day_finished(&outcome);
+            S::finalize(&outcome);
+
+            if let Some(limit) = options.max_regression {
+                regressions.extend(find_regressions(&outcome, limit));
+            }
+
+            if options.save_baseline {
+                baseline.record(&outcome);
+            }
+        });
+        // The baseline is still worth saving if the report couldn't be written.
+        let reported = reporter.run_end();
+
+        if options.save_baseline {
+            baseline
+                .save(&options.baseline)
+                .map_err(|err| format!("could not save baseline to {}: {err}", options.baseline.display()))?;
+
+            eprintln!("\nSaved baseline to {}", options.baseline.display());
+        }
+
+        reported?;
+
+        match regressions.is_empty() {
+            true => Ok(()),
+            false => Err(format!("performance regressed beyond the allowed limit:\n{}", regressions.join("\n")))
+        }
+    }
+
+    /// Solves, pretty-prints and finalizes the specified day, if a solution for it exists.
+    pub fn solve(&self, day: u8) -> Option<Outcome<String>> {
+        let entry = self.entries
+            .iter()
+            .find(|entry| entry.day == day)?;
+
+        let outcome = self.execute(entry, None, &mut |event| event.send(&mut PrettyReporter));
+        PrettyReporter.day_finished(&outcome);
+        S::finalize(&outcome);
+        Some(outcome)
+    }
+
+    /// Solves, pretty-prints and finalizes every day in order.
+    pub fn solve_all(&self) -> Vec<Outcome<String>> {
+        self.entries
+            .iter()
+            .filter_map(|entry| self.solve(entry.day))
+            .collect()
+    }
+
+    /// Silently solves every day, then compares the outcomes against the [verified answers](Solver::verified)
+    /// to produce a progress report.
+    pub fn progress(&self) -> Progress {
+        self.progress_with(&Options::default())
+    }
+
+    fn progress_with(&self, options: &Options) -> Progress {
+        let mut progress = Progress::new(S::YEAR);
+        let entries: Vec<_> = self.entries
+            .iter()
+            .collect();
+
+        self.execute_all(&entries, options, |event| {
+            let Event::DayFinished(outcome) = event else {
+                return;
+            };
+
+            let verified = [
+                S::verified(outcome.day, PART_ONE),
+                S::verified(outcome.day, PART_TWO)
+            ];
+
+            progress.record(&outcome, verified);
+        });
+
+        progress
+    }
+}
+
+impl<S: Solver> Runner<S> {
+    /// Executes every entry, passing the events of each (ending with its outcome) to the callback in order.
+    /// 
+    /// Unless `options.jobs` is one, entries are executed concurrently on a pool of threads;
+    /// events are buffered until every preceding entry has finished.
+    fn execute_all(&self, entries: &[&Entry], options: &Options, mut callback: impl FnMut(Event)) {
+        let jobs = options.jobs.clamp(1, entries.len().max(1));
+
+        if jobs == 1 {
+            for entry in entries {
+                let outcome = self.execute(entry, options.timeout, &mut callback);
+                callback(Event::DayFinished(Box::new(outcome)));
+            }
+            return;
+        }
+
+        let next = &AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs {
+                let sender = sender.clone();
+                
+                scope.spawn(move || {
+                    while let Some(entry) = entries.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let mut outcome = self.execute(entry, options.timeout, &mut |event| {
+                            let _ = sender.send(event);
+                        });
+                        // Heap counters are process-wide, so they're meaningless when days run concurrently.
+                        outcome.allocations = None;
+                        
+                        if sender.send(Event::DayFinished(Box::new(outcome))).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut buffer: BTreeMap<u8, Vec<Event>> = BTreeMap::new();
+            let mut order = entries.iter().map(|entry| entry.day).peekable();
+
+            for event in receiver {
+                buffer.entry(event.day()).or_default().push(event);
+
+                // Forward everything so far from the earliest unfinished day, moving on whenever it finishes.
+                while let Some(events) = order.peek().and_then(|day| buffer.remove(day)) {
+                    let mut finished = false;
+                    for event in events {
+                        finished |= matches!(event, Event::DayFinished(_));
+                        callback(event);
+                    }
+
+                    if !finished {
+                        break;
+                    }
+                    order.next();
+                }
+            }
+        });
+    }
+
+    /// Executes a single entry, passing its start and each finished part to `on_event` as they happen.
+    fn execute(&self, entry: &Entry, timeout: Option<Duration>, on_event: &mut dyn FnMut(Event)) -> Outcome<String> {
+        on_event(Event::DayStart(entry.day));
+
+        let puzzle = S::load(entry.day);
+        let mut outcome = Outcome::new(entry.day);
+        let mut record = |step: Step<String>| {
+            if let Step::Part { part, answer, .. } = &step {
+                on_event(Event::PartFinished(entry.day, *part, answer.clone()));
+            }
+            outcome.record(step);
+        };
+
+        match timeout {
+            Some(limit) => execute_with_timeout(entry, &puzzle, limit, &mut record),
+            None => (entry.steps)(&puzzle, [true, true], &mut record)
+        }
+
+        outcome
+    }
+}
+
+// Set when re-running in release mode, in case the release profile enables debug assertions.
+const RELEASE_RERUN: &str = "LIB_AOC_RELEASE_RERUN";
+
+/// Re-runs the program in release mode using Cargo, with the same arguments, returning its exit code.
+fn run_in_release() -> Result<i32, String> {
+    if env::var_os(RELEASE_RERUN).is_some() {
+        return Err("the release profile has debug assertions enabled, so it can't be benchmarked.".to_string());
+    }
+
+    let (Some(cargo), Some(manifest)) = (env::var_os("CARGO"), env::var_os("CARGO_MANIFEST_DIR")) else {
+        return Err("re-running in release mode requires starting the program with 'cargo run'.".to_string());
+    };
+
+    let binary = env::current_exe()
+        .ok()
+        .and_then(|path| path.file_stem().map(OsStr::to_os_string))
+        .ok_or("could not determine the name of the current executable.")?;
+
+    eprintln!("{}", "Re-running in release mode...".dimmed());
+
+    process::Command::new(cargo)
+        .args(["run", "--release", "--quiet", "--manifest-path"])
+        .arg(Path::new(&manifest).join("Cargo.toml"))
+        .arg("--bin")
+        .arg(binary)
+        .arg("--")
+        .args(env::args_os().skip(1))
+        .env(RELEASE_RERUN, "1")
+        .status()
+        .map(|status| status.code().unwrap_or(1))
+        .map_err(|err| format!("could not run cargo: {err}"))
+}
+
+/// Executes a solution on a worker thread, abandoning any phase that exceeds the time limit, and passes
+/// each completed (or abandoned) phase to the sink.
+/// 
+/// If a part is abandoned, the remaining parts are executed on a fresh worker (which re-parses
+/// the input.) Abandoned workers cannot be stopped, and keep running in the background until the process exits.
+fn execute_with_timeout(entry: &Entry, puzzle: &str, limit: Duration, sink: &mut dyn FnMut(Step<String>)) {
+    let mut pending = [true, true];
+    let mut parsed = false;
+
+    while pending.contains(&true) {
+        let (sender, receiver) = mpsc::channel();
+        let steps = entry.steps;
+        let puzzle = puzzle.to_string();
+        let parts = pending;
+
+        let worker = thread::spawn(move || {
+            steps(&puzzle, parts, &mut |step| {
+                // The receiver is gone if this worker was abandoned.
+                let _ = sender.send(step);
+            })
+        });
+
+        loop {
+            match receiver.recv_timeout(limit) {
+                Ok(step) => {
+                    match &step {
+                        // Only the first worker's parsing phase is recorded.
+                        Step::Parsed { .. } if parsed => continue,
+                        Step::Parsed { .. } => parsed = true,
+                        Step::Part { part, .. } => pending[*part as usize] = false
+                    }
+                    sink(step);
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    // If parsing never finished, none of the pending parts can run;
+                    // otherwise, only the first pending part is stuck.
+                    let abandoned: Vec<_> = [PART_ONE, PART_TWO]
+                        .into_iter()
+                        .filter(|part| pending[*part as usize])
+                        .take(if parsed { 1 } else { 2 })
+                        .collect();
+
+                    for part in abandoned {
+                        pending[part as usize] = false;
+                        // A part that doesn't exist can't time out, even if parsing did.
+                        let answer = match part == PART_TWO && !entry.has_part_two {
+                            true => Answer::Absent,
+                            false => Answer::TimedOut(limit)
+                        };
+
+                        sink(Step::Part {
+                            part,
+                            answer,
+                            phase: None,
+                            heap: None,
+                            caches: Vec::new()
+                        });
+                    }
+                    break;
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    // Propagate panics from the worker, just like if the solution was executed inline.
+                    if let Err(panic) = worker.join() {
+                        panic::resume_unwind(panic);
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Describes every top-level phase (and the total) that regressed by more than `limit` percent.
+fn find_regressions(outcome: &Outcome<String>, limit: f64) -> Vec<String> {
+    let timings = &outcome.timings;
+    
+    timings.phases
+        .iter()
+        .map(|phase| (phase.name.as_ref(), phase.delta))
+        .chain(std::iter::once(("Total", timings.delta)))
+        .filter_map(|(name, delta)| {
+            delta
+                .filter(|delta| delta.is_regression() && delta.percent > limit)
+                .map(|delta| format!("  day {} {name}: {:+.1}%", outcome.day, delta.percent))
+        })
+        .collect()
+}
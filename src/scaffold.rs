@@ -0,0 +1,219 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf}
+};
+
+/// The template used to generate new day modules, unless overriden.
+pub(crate) const DEFAULT_TEMPLATE: &str = "\
+use lib_aoc::prelude::*;
+
+use crate::Solutions;
+
+impl Solution<{{const}}> for Solutions {
+    type Input<'i> = Vec<&'i str>;
+    type Output = u64;
+
+    fn try_parse(puzzle: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(puzzle
+            .lines()
+            .collect())
+    }
+
+    // Parts that haven't been implemented yet are reported as such.
+    // fn part_one(input: &Self::Input<'_>) -> Self::Output {}
+    // fn part_two(input: &Self::Input<'_>) -> Self::Output {}
+}
+
+impl Test<{{const}}> for Solutions {
+    fn expected(part: bool) -> Self::Output {
+        match part {
+            PART_ONE => unimplemented!(),
+            PART_TWO => unimplemented!()
+        }
+    }
+}
+
+derive_tests!(Solutions, {{const}});
+";
+
+/// Describes where to generate files when scaffolding a day.
+pub(crate) struct Layout {
+    /// The directory to create the day's module in.
+    pub modules: PathBuf,
+    /// The crate root to register the module in (`main.rs` or `lib.rs`.)
+    pub root: PathBuf,
+    /// The input files to create.
+    pub inputs: Vec<PathBuf>
+}
+
+/// Fills in a template's placeholders for the specified day.
+pub(crate) fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{const}}", &format!("DAY_{day:02}"))
+        .replace("{{day:02}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Generates the module and input files for a day, then registers the module with the crate root.
+///
+/// Returns a description of each action taken. Fails without changing anything if the day is not on the calendar
+/// or the module already exists; input files that already exist are left untouched, and files that were created
+/// are removed again if the module can't be registered.
+pub(crate) fn scaffold(day: u8, template: &str, layout: &Layout) -> Result<Vec<String>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not on the calendar; days range from 1 to 25."));
+    }
+
+    let name = format!("day{day:02}");
+    let module = layout.modules.join(format!("{name}.rs"));
+
+    if module.exists() {
+        return Err(format!("{} already exists.", module.display()));
+    }
+
+    let root = fs::read_to_string(&layout.root)
+        .map_err(|err| format!("could not read {}: {err}", layout.root.display()))?;
+
+    let mut actions = Vec::new();
+    let mut created = Vec::new();
+
+    // Don't leave behind files that the crate root doesn't know about.
+    let undo = |created: &[&Path]| {
+        for path in created {
+            let _ = fs::remove_file(path);
+        }
+    };
+
+    write_new(&module, &render(template, day))?;
+    actions.push(format!("Created {}", module.display()));
+    created.push(module.as_path());
+
+    for input in &layout.inputs {
+        match input.exists() {
+            true => actions.push(format!("Skipped {} (already exists)", input.display())),
+            false => {
+                write_new(input, "").inspect_err(|_| undo(&created))?;
+                actions.push(format!("Created {}", input.display()));
+                created.push(input);
+            }
+        }
+    }
+
+    let (registered, notes) = register(&root, &name, day);
+    if registered != root {
+        fs::write(&layout.root, registered)
+            .map_err(|err| format!("could not write {}: {err}", layout.root.display()))
+            .inspect_err(|_| undo(&created))?;
+
+        actions.push(format!("Registered {name} in {}", layout.root.display()));
+    }
+
+    actions.extend(notes);
+    Ok(actions)
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("could not create {}: {err}", parent.display()))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// Declares the module in the crate root, and raises the day given to `runner!` or `solve_through!`
+/// so that it includes the new day.
+///
+/// Returns the new crate root, along with notes on anything that has to be done by hand: the day is only raised
+/// if the new day directly follows it, since every day up to it needs a `Solution`.
+fn register(root: &str, name: &str, day: u8) -> (String, Vec<String>) {
+    let declaration = format!("mod {name};");
+    let mut lines: Vec<String> = root
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    if !lines.iter().any(|line| line.trim() == declaration) {
+        match lines.iter().rposition(|line| line.starts_with("mod ") || line.starts_with("pub mod ")) {
+            Some(index) => lines.insert(index + 1, declaration),
+            None => {
+                // Keep the crate's inner docs and imports at the top, separated from the new declaration.
+                let position = declaration_position(&lines);
+                let blank = |line: Option<&String>| line.is_none_or(|line| line.trim().is_empty());
+
+                if !blank(lines.get(position)) {
+                    lines.insert(position, String::new());
+                }
+                lines.insert(position, declaration);
+                if !blank(position.checked_sub(1).and_then(|index| lines.get(index))) {
+                    lines.insert(position, String::new());
+                }
+            }
+        }
+    }
+
+    let mut notes = Vec::new();
+
+    for line in &mut lines {
+        for invocation in ["runner!(", "solve_through!("] {
+            let Some((current, range)) = last_day(line, invocation) else {
+                continue;
+            };
+
+            match current.checked_add(1) {
+                Some(next) if next == day => line.replace_range(range, &format!(" {day}")),
+                Some(next) if next < day => {
+                    let missing = match next == day - 1 {
+                        true => format!("day {next}"),
+                        false => format!("days {next} to {}", day - 1)
+                    };
+                    notes.push(format!("Left {invocation}...) at day {current}: implement {missing} before raising it to {day}."));
+                },
+                _ => ()
+            }
+        }
+    }
+
+    let mut output = lines.join("\n");
+    if root.ends_with('\n') {
+        output.push('\n');
+    }
+    (output, notes)
+}
+
+/// Finds the line after the last top-level `use` item, or failing that, after the leading inner
+/// doc comments and attributes.
+fn declaration_position(lines: &[String]) -> usize {
+    let last_use = lines
+        .iter()
+        .rposition(|line| line.starts_with("use ") || line.starts_with("pub use "));
+
+    match last_use {
+        // A `use` item may span several lines, up to its semicolon.
+        Some(index) => lines[index..]
+            .iter()
+            .position(|line| line.trim_end().ends_with(';'))
+            .map_or(lines.len(), |offset| index + offset + 1),
+        None => lines
+            .iter()
+            .position(|line| !(line.starts_with("//!") || line.starts_with("#![")))
+            .unwrap_or(lines.len())
+    }
+}
+
+/// Finds the last argument of a macro invocation, if it is an integer literal, returning it along with
+/// the range of text after the final comma.
+fn last_day(line: &str, invocation: &str) -> Option<(u8, Range<usize>)> {
+    let start = line.find(invocation)? + invocation.len();
+    let end = start + line[start..].find(')')?;
+    let comma = start + line[start..end].rfind(',')?;
+
+    let current = line[comma + 1..end]
+        .trim()
+        .parse::<u8>()
+        .ok()?;
+
+    Some((current, comma + 1..end))
+}
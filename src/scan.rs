@@ -0,0 +1,201 @@
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::Display,
+    str::FromStr
+};
+
+/// Describes why puzzle input could not be parsed, and where.
+///
+/// Returned by [`Solution::try_parse`](crate::Solution::try_parse); the runner reports it in place of each part's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>
+}
+
+impl ParseError {
+    /// Creates an error with the given message, without a location.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None
+        }
+    }
+
+    /// Sets the (one-based) line on which the error occurred.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the (one-based) column, in characters, at which the error occurred.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Describes what went wrong, without the location.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line on which the error occurred, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// The column at which the error occurred, if known.
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => ()
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses each line of the puzzle with the provided closure, collecting the results.
+///
+/// Errors that don't already have a line number are given the (one-based) number of the line that produced them.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// let puzzle = "1-3 a\n2-9 c\n4 b";
+/// let result: Result<Vec<_>, _> = scan_lines(puzzle, |line| scan!("{}-{} {}", line => u8, u8, char));
+///
+/// assert_eq!(result.unwrap_err().to_string(), "line 3, column 2: expected `-`, found ` b`");
+/// ```
+pub fn scan_lines<'p, T, C>(puzzle: &'p str, mut op: impl FnMut(&'p str) -> Result<T, ParseError>) -> Result<C, ParseError> where
+    C: FromIterator<T>
+{
+    puzzle
+        .lines()
+        .enumerate()
+        .map(|(index, line)| op(line).map_err(|err| match err.line {
+            Some(_) => err,
+            None => err.at_line(index + 1)
+        }))
+        .collect()
+}
+
+/// Matches a line against a [`scan!`](crate::scan) pattern, one placeholder at a time.
+#[doc(hidden)]
+pub struct Scanner<'a> {
+    pattern: &'static str,
+    line: &'a str,
+    position: usize
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(pattern: &'static str, line: &'a str) -> Self {
+        Self {
+            pattern,
+            line: line.trim_end(),
+            position: 0
+        }
+    }
+
+    /// Parses the next placeholder's field, after matching the literal text that precedes it.
+    pub fn field<T>(&mut self) -> Result<T, ParseError> where
+        T: FromStr,
+        T::Err: Display
+    {
+        self.literal()?;
+
+        self.pattern = self.pattern
+            .strip_prefix("{}")
+            .expect("scan! pattern should have a placeholder for every type");
+
+        let next = self.next_literal();
+        assert!(
+            !next.is_empty() || self.pattern.is_empty(),
+            "scan! placeholders should be separated by literal text"
+        );
+
+        // Fields extend up to the next literal. If it's missing, stop at the first character that could
+        // start it (or whitespace), so that the error points at the literal rather than the field.
+        let rest = &self.line[self.position..];
+        let length = match next {
+            "" => rest.len(),
+            next => rest
+                .find(next)
+                .or_else(|| rest.find(|c: char| c.is_whitespace() || next.starts_with(c)))
+                .unwrap_or(rest.len())
+        };
+
+        let start = self.column();
+        let field = &rest[..length];
+        self.position += length;
+
+        field.parse().map_err(|err| {
+            ParseError::new(format!("could not parse `{field}` as {}: {err}", short_type_name::<T>()))
+                .at_column(start)
+        })
+    }
+
+    /// Matches any remaining literal text, then checks that nothing is left over.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        self.literal()?;
+        assert!(self.pattern.is_empty(), "scan! pattern should have a type for every placeholder");
+
+        match &self.line[self.position..] {
+            "" => Ok(()),
+            rest => Err(ParseError::new(format!("unexpected trailing `{rest}`")).at_column(self.column()))
+        }
+    }
+
+    fn next_literal(&self) -> &'static str {
+        let end = self.pattern
+            .find("{}")
+            .unwrap_or(self.pattern.len());
+
+        &self.pattern[..end]
+    }
+
+    fn literal(&mut self) -> Result<(), ParseError> {
+        let literal = self.next_literal();
+        let rest = &self.line[self.position..];
+
+        if !rest.starts_with(literal) {
+            let found = match rest {
+                "" => "end of line".to_string(),
+                rest => format!("`{rest}`")
+            };
+
+            return Err(ParseError::new(format!("expected `{literal}`, found {found}")).at_column(self.column()));
+        }
+
+        self.pattern = &self.pattern[literal.len()..];
+        self.position += literal.len();
+        Ok(())
+    }
+
+    fn column(&self) -> usize {
+        self.line[..self.position].chars().count() + 1
+    }
+}
+
+/// Strips the module path from a type's name, e.g. `alloc::string::String` becomes `String`.
+fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+
+    match name.contains('<') {
+        true => name,
+        false => name.rsplit("::").next().unwrap_or(name)
+    }
+}
@@ -0,0 +1,425 @@
+//! Generic graph searches over a successor function.
+//!
+//! Nodes can be any hashable type - a position, or an entire puzzle state. Rather than building a graph up front,
+//! each search takes a closure which produces the successors of a node, so they work directly with the
+//! [`Grid`](crate::grid::Grid) and [`Point2`](crate::geom::Point2) types. Searches that find a goal return the
+//! [`Path`] to it, including both ends.
+//!
+//! Example usage:
+//! ```
+//! # use lib_aoc::prelude::*;
+//! # use lib_aoc::search;
+//! let maze = Grid::parse("S.#\n#..\n#.E", |c| c);
+//! let start = maze.find(&'S').unwrap();
+//! let end = maze.find(&'E').unwrap();
+//!
+//! let path = search::bfs(
+//!     start,
+//!     |&position| maze
+//!         .neighbours4(position)
+//!         .filter(|(_, &cell)| cell != '#')
+//!         .map(|(next, _)| next),
+//!     |&position| position == end
+//! ).unwrap();
+//!
+//! assert_eq!(path.cost, 4);
+//! assert_eq!(path.nodes.first(), Some(&start));
+//! assert_eq!(path.nodes.last(), Some(&end));
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add
+};
+
+/// The cost of an edge, such as a distance or a duration.
+///
+/// Implemented for every type that can be added, compared and defaults to zero, which includes the primitive integers.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = Self>> Cost for C {}
+
+/// A path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// The total cost of the path; for unweighted searches, the number of steps.
+    pub cost: C,
+    /// Every node along the path, from the start to the goal.
+    pub nodes: Vec<N>
+}
+
+/// The nodes discovered by a search, along with how each was reached.
+struct Tree<N, C> {
+    nodes: Vec<(N, usize, C)>,
+    indices: HashMap<N, usize>
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Tree<N, C> {
+    fn new(root: N) -> Self {
+        Self {
+            nodes: vec![(root.clone(), 0, C::default())],
+            indices: HashMap::from([(root, 0)])
+        }
+    }
+
+    /// Records a newly discovered node, returning its index, or `None` if it was already discovered.
+    fn discover(&mut self, node: N, parent: usize, cost: C) -> Option<usize> {
+        if self.indices.contains_key(&node) {
+            return None;
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push((node, parent, cost));
+        Some(index)
+    }
+
+    fn node(&self, index: usize) -> &N {
+        &self.nodes[index].0
+    }
+
+    fn cost(&self, index: usize) -> C {
+        self.nodes[index].2
+    }
+
+    /// Returns the nodes from the root to the node at `index`.
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].0.clone()];
+
+        while index != 0 {
+            index = self.nodes[index].1;
+            path.push(self.nodes[index].0.clone());
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Finds a path with the fewest steps from `start` to a node satisfying `goal`, using breadth-first search.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut goal: impl FnMut(&N) -> bool) -> Option<Path<N, usize>> where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>
+{
+    let mut tree = Tree::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if goal(tree.node(index)) {
+            return Some(Path { cost: tree.cost(index), nodes: tree.path(index) });
+        }
+
+        let cost = tree.cost(index) + 1;
+        for next in successors(tree.node(index)) {
+            queue.extend(tree.discover(next, index, cost));
+        }
+    }
+
+    None
+}
+
+/// Finds any path from `start` to a node satisfying `goal`, using depth-first search.
+///
+/// The path is not necessarily the shortest, but depth-first search can find one with much less memory
+/// when the goal is far away.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut goal: impl FnMut(&N) -> bool) -> Option<Path<N, usize>> where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>
+{
+    let mut tree = Tree::new(start);
+    let mut stack = vec![0];
+
+    while let Some(index) = stack.pop() {
+        if goal(tree.node(index)) {
+            return Some(Path { cost: tree.cost(index), nodes: tree.path(index) });
+        }
+
+        let cost = tree.cost(index) + 1;
+        for next in successors(tree.node(index)) {
+            stack.extend(tree.discover(next, index, cost));
+        }
+    }
+
+    None
+}
+
+/// Finds a cheapest path from `start` to a node satisfying `goal`, using Dijkstra's algorithm.
+///
+/// `successors` returns each neighbouring node along with the cost of moving to it, which must not be negative.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// # use lib_aoc::search;
+/// let risk = digit_grid("116\n138\n213");
+/// let end = (risk.width() - 1, risk.height() - 1);
+///
+/// let path = search::dijkstra(
+///     (0, 0),
+///     |&position| risk.neighbours4(position).map(|(next, &cost)| (next, cost as u32)),
+///     |&position| position == end
+/// ).unwrap();
+///
+/// assert_eq!(path.cost, 7);
+/// assert_eq!(path.nodes, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+/// ```
+pub fn dijkstra<N, C, I>(start: N, successors: impl FnMut(&N) -> I, goal: impl FnMut(&N) -> bool) -> Option<Path<N, C>> where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// Finds a cheapest path from `start` to a node satisfying `goal`, using A* search.
+///
+/// `heuristic` estimates the remaining cost from a node to the goal. For the path to be the cheapest, it must never
+/// overestimate; for example, the [Manhattan distance](crate::geom::Point2::manhattan) on a grid where each step costs one.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// # use lib_aoc::search;
+/// let walls: SparseGrid<char> = SparseGrid::parse("..#..\n..#..\n.....", |c| (c == '#').then_some(c));
+/// let end = Point2::new(4i32, 0);
+///
+/// let path = search::astar(
+///     Point2::new(0, 0),
+///     |&point| point
+///         .neighbours4()
+///         .filter(|&next| next.x >= 0 && next.x < 5 && next.y >= 0 && next.y < 3 && !walls.contains(next))
+///         .map(|next| (next, 1)),
+///     |&point| point.manhattan(end),
+///     |&point| point == end
+/// ).unwrap();
+///
+/// assert_eq!(path.cost, 8);
+/// ```
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool
+) -> Option<Path<N, C>> where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>
+{
+    let mut tree = Tree::new(start);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(tree.node(0)), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // Skip entries superseded by a cheaper route to the same node.
+        if cost > tree.cost(index) {
+            continue;
+        }
+
+        if goal(tree.node(index)) {
+            return Some(Path { cost, nodes: tree.path(index) });
+        }
+
+        for (next, step) in successors(tree.node(index)) {
+            let next_cost = cost + step;
+
+            let next_index = match tree.indices.get(&next) {
+                Some(&existing) if tree.cost(existing) <= next_cost => continue,
+                Some(&existing) => {
+                    tree.nodes[existing].1 = index;
+                    tree.nodes[existing].2 = next_cost;
+                    existing
+                },
+                None => tree
+                    .discover(next, index, next_cost)
+                    .expect("Node should not have been discovered yet")
+            };
+
+            let estimate = heuristic(tree.node(next_index));
+            heap.push(Reverse((next_cost + estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Finds a path with the fewest steps between `start` and `goal`, searching outwards from both ends at once.
+///
+/// `predecessors` returns the nodes that can move *to* a node; when moves can be reversed, this is the same as `successors`.
+/// This can explore far fewer nodes than [`bfs`] when the number of successors grows quickly with distance.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::search;
+/// // Each number can be doubled or incremented.
+/// let successors = |&n: &u64| [n * 2, n + 1];
+/// let predecessors = |&n: &u64| [(n % 2 == 0).then(|| n / 2), n.checked_sub(1)].into_iter().flatten();
+///
+/// let path = search::bidirectional_bfs(1, 37, successors, predecessors).unwrap();
+/// assert_eq!(path.nodes, [1, 2, 4, 8, 9, 18, 36, 37]);
+/// ```
+pub fn bidirectional_bfs<N, I, J>(
+    start: N,
+    goal: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut predecessors: impl FnMut(&N) -> J
+) -> Option<Path<N, usize>> where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    J: IntoIterator<Item = N>
+{
+    if start == goal {
+        return Some(Path { cost: 0, nodes: vec![start] });
+    }
+
+    let mut forward = (Tree::<N, usize>::new(start), vec![0]);
+    let mut backward = (Tree::<N, usize>::new(goal), vec![0]);
+
+    while !forward.1.is_empty() && !backward.1.is_empty() {
+        // Expand whichever frontier is smaller by one whole level, tracking the shortest meeting point.
+        let expand_forward = forward.1.len() <= backward.1.len();
+        let ((tree, frontier), (other, _)) = match expand_forward {
+            true => (&mut forward, &backward),
+            false => (&mut backward, &forward)
+        };
+
+        let mut best: Option<(usize, usize, usize)> = None;
+        let mut next_frontier = Vec::new();
+
+        for index in std::mem::take(frontier) {
+            let cost = tree.cost(index) + 1;
+            let neighbours: Vec<N> = match expand_forward {
+                true => successors(tree.node(index)).into_iter().collect(),
+                false => predecessors(tree.node(index)).into_iter().collect()
+            };
+
+            for next in neighbours {
+                if let Some(&meeting) = other.indices.get(&next) {
+                    let total = cost + other.cost(meeting);
+                    if best.is_none_or(|(best, ..)| total < best) {
+                        best = Some((total, index, meeting));
+                    }
+                }
+
+                next_frontier.extend(tree.discover(next, index, cost));
+            }
+        }
+
+        *frontier = next_frontier;
+
+        if let Some((cost, index, meeting)) = best {
+            let (near, far) = (tree.path(index), other.path(meeting));
+
+            let nodes = match expand_forward {
+                true => near.into_iter().chain(far.into_iter().rev()).collect(),
+                false => far.into_iter().chain(near.into_iter().rev()).collect()
+            };
+
+            return Some(Path { cost, nodes });
+        }
+    }
+
+    None
+}
+
+/// Finds the cost of the cheapest paths from `start` to a node satisfying `goal`, and how many such paths there are.
+///
+/// `successors` returns each neighbouring node along with the cost of moving to it, which must be positive.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::search;
+/// // Moving right or down through a 3x3 grid.
+/// let successors = |&(x, y): &(u8, u8)| [(x + 1, y), (x, y + 1)]
+///     .into_iter()
+///     .filter(|&(x, y)| x < 3 && y < 3)
+///     .map(|next| (next, 1));
+///
+/// assert_eq!(search::count_shortest_paths((0, 0), successors, |&node| node == (2, 2)), Some((4, 6)));
+/// ```
+pub fn count_shortest_paths<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool
+) -> Option<(C, u64)> where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>
+{
+    let mut tree = Tree::new(start);
+    let mut counts = vec![1];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if cost > tree.cost(index) {
+            continue;
+        }
+
+        if goal(tree.node(index)) {
+            return Some((cost, counts[index]));
+        }
+
+        for (next, step) in successors(tree.node(index)) {
+            let next_cost = cost + step;
+
+            match tree.indices.get(&next) {
+                Some(&existing) if tree.cost(existing) < next_cost => (),
+                Some(&existing) if tree.cost(existing) == next_cost => counts[existing] += counts[index],
+                Some(&existing) => {
+                    tree.nodes[existing].2 = next_cost;
+                    counts[existing] = counts[index];
+                    heap.push(Reverse((next_cost, existing)));
+                },
+                None => {
+                    let discovered = tree
+                        .discover(next, index, next_cost)
+                        .expect("Node should not have been discovered yet");
+
+                    counts.push(counts[index]);
+                    heap.push(Reverse((next_cost, discovered)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds every node reachable from `start`, along with the fewest steps needed to reach it.
+///
+/// Example usage:
+/// ```
+/// # use lib_aoc::prelude::*;
+/// # use lib_aoc::search;
+/// let garden = Grid::parse("...\n.#.\n..S", |c| c);
+/// let start = garden.find(&'S').unwrap();
+///
+/// let steps = search::reachable(start, |&position| garden
+///     .neighbours4(position)
+///     .filter(|(_, &cell)| cell != '#')
+///     .map(|(next, _)| next)
+/// );
+///
+/// assert_eq!(steps.len(), 8);
+/// assert_eq!(steps[&(0, 0)], 4);
+/// ```
+pub fn reachable<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize> where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let next_steps = steps[&node] + 1;
+
+        for next in successors(&node) {
+            if !steps.contains_key(&next) {
+                steps.insert(next.clone(), next_steps);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    steps
+}
@@ -1,38 +1,153 @@
-use std::time::{Instant, Duration};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    marker::PhantomData,
+    time::Instant
+};
 
-pub struct Timer {
+use crate::{memory, outcome::Phase};
+
+pub(crate) const PARSING: &str = "Parsing";
+pub(crate) const PART_ONE: &str = "Part 1";
+pub(crate) const PART_TWO: &str = "Part 2";
+
+thread_local! {
+    // Stack of phases that are currently being timed on this thread.
+    static FRAMES: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Frame {
+    name: Cow<'static, str>,
     start: Instant,
-    prev: Instant,
-    buffer: Vec<(&'static str, Duration)>
+    children: Vec<Phase>
+}
+
+impl Frame {
+    fn new(name: Cow<'static, str>) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+            children: Vec::new()
+        }
+    }
+
+    fn into_phase(self) -> Phase {
+        Phase {
+            name: self.name,
+            duration: self.start.elapsed(),
+            count: 1,
+            children: self.children,
+            delta: None
+        }
+    }
+}
+
+/// Pushes a new frame onto the current thread's stack, returning its depth.
+/// 
+/// Like [`close`], this is bookkeeping rather than part of the solution, so its allocations aren't profiled.
+fn open(name: Cow<'static, str>) -> usize {
+    memory::untracked(|| FRAMES.with_borrow_mut(|frames| {
+        frames.push(Frame::new(name));
+        frames.len()
+    }))
+}
+
+/// Closes the frame at the specified depth (and any frames above it that were
+/// never closed), attaching it to its parent if `attach` is set.
+fn close(depth: usize, attach: bool) -> Option<Phase> {
+    memory::untracked(|| FRAMES.with_borrow_mut(|frames| {
+        while frames.len() > depth {
+            let orphan = frames.pop()?.into_phase();
+            merge(&mut frames.last_mut()?.children, orphan);
+        }
+
+        if frames.len() != depth {
+            return None;
+        }
+
+        let phase = frames.pop()?.into_phase();
+        match (attach, frames.last_mut()) {
+            (true, Some(parent)) => {
+                merge(&mut parent.children, phase);
+                None
+            },
+            _ => Some(phase)
+        }
+    }))
 }
 
-impl Timer {
-    pub(crate) fn new() -> Self {
-        let buffer = Vec::with_capacity(4);
-        let init = Instant::now();
-        Self { 
-            start: init,
-            prev: init,
-            buffer
+/// Executes the provided closure as a new top-level phase on the current thread,
+/// returning its result and the recorded phase (including any nested spans.)
+pub(crate) fn measure<T>(name: &'static str, operation: impl FnOnce() -> T) -> (T, Phase) {
+    let mut guard = FrameGuard { depth: Some(open(name.into())) };
+    let value = operation();
+    let phase = guard.depth
+        .take()
+        .and_then(|depth| close(depth, false))
+        .expect("Phase frame should be open");
+
+    (value, phase)
+}
+
+/// Closes a phase's frame if the operation being measured panics, so that it isn't
+/// left on the stack to collect spans opened later on the same thread.
+struct FrameGuard {
+    depth: Option<usize>
+}
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        if let Some(depth) = self.depth {
+            close(depth, false);
         }
     }
+}
 
-    pub(crate) fn buffer(&self) -> &[(&'static str, Duration)] {
-        &self.buffer
+/// Guard that times a named phase of a solution until it is dropped.
+/// 
+/// Spans nest, and are recorded into the [`Timings`](crate::prelude::Timings) of the solution currently executing
+/// on the same thread; outside of a solution (e.g. in tests) they are a no-op.
+/// Spans with the same name and parent are merged, so it's fine to create one inside a loop.
+/// 
+/// Typically created using the [`span`](crate::span) macro.
+#[must_use = "a span only measures time until it is dropped"]
+pub struct Span {
+    depth: usize,
+    // Spans refer to thread-local state, and so must not be sent to other threads.
+    thread: PhantomData<*const ()>
+}
+
+impl Span {
+    /// Begins timing a new phase with the specified name.
+    pub fn enter(name: impl Into<Cow<'static, str>>) -> Self {
+        let active = FRAMES.with_borrow(|frames| !frames.is_empty());
+
+        Self {
+            depth: if active { open(name.into()) } else { 0 },
+            thread: PhantomData
+        }
     }
+}
 
-    pub(crate) fn mark(&mut self, label: &'static str) {
-        self.buffer.push((
-            label,
-            self.prev.elapsed()
-        ));
-        self.prev = Instant::now();
+impl Drop for Span {
+    fn drop(&mut self) {
+        if self.depth != 0 {
+            close(self.depth, true);
+        }
     }
+}
 
-    pub(crate) fn mark_total(&mut self, label: &'static str) {
-        self.buffer.push((
-            label,
-            self.start.elapsed()
-        ));
+/// Adds a phase to a list of siblings, combining it with any existing phase of the same name.
+fn merge(siblings: &mut Vec<Phase>, phase: Phase) {
+    match siblings.iter_mut().find(|existing| existing.name == phase.name) {
+        Some(existing) => {
+            existing.duration += phase.duration;
+            existing.count += phase.count;
+            
+            for child in phase.children {
+                merge(&mut existing.children, child);
+            }
+        },
+        None => siblings.push(phase)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,124 @@
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    time::SystemTime
+};
+use colored::Colorize;
+
+use crate::outcome::Outcome;
+
+/// The modification times of a set of files, used to detect changes.
+#[derive(PartialEq, Eq)]
+pub(crate) struct Snapshot(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl Snapshot {
+    /// Records the modification times of the specified paths, recursing into directories.
+    pub(crate) fn take(paths: &[PathBuf]) -> Self {
+        let mut times = Vec::new();
+        for path in paths {
+            collect(path, &mut times);
+        }
+        Self(times)
+    }
+}
+
+fn collect(path: &Path, times: &mut Vec<(PathBuf, Option<SystemTime>)>) {
+    match fs::read_dir(path) {
+        Ok(entries) => {
+            let mut entries: Vec<_> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .collect();
+
+            entries.sort();
+            for entry in entries {
+                collect(&entry, times);
+            }
+        },
+        Err(_) => {
+            let modified = fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+
+            times.push((path.to_path_buf(), modified));
+        }
+    }
+}
+
+/// The source files to watch when rebuilding is enabled.
+pub(crate) fn sources() -> Vec<PathBuf> {
+    vec![
+        PathBuf::from("src"),
+        PathBuf::from("Cargo.toml")
+    ]
+}
+
+pub(crate) fn clear_screen() {
+    print!("\x1B[2J\x1B[H");
+}
+
+/// Describes how the answers changed between two outcomes of the same solution.
+pub(crate) fn changes(previous: &Outcome<String>, current: &Outcome<String>) -> String {
+    let mut output = String::new();
+    let parts = [
+        ("Part 1", &previous.part_one, &current.part_one),
+        ("Part 2", &previous.part_two, &current.part_two)
+    ];
+
+    for (name, before, after) in parts {
+        if before != after {
+            let _ = writeln!(output, "{}: {} -> {}", name.bold(), before.to_string().dimmed(), after.to_string().yellow());
+        }
+    }
+
+    match output.is_empty() {
+        true => format!("{}\n", "Answers unchanged.".dimmed()),
+        false => output
+    }
+}
+
+/// Rebuilds the program using Cargo, returning `true` on success.
+pub(crate) fn rebuild() -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.arg("build");
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Replaces the current process with a fresh instance of the (rebuilt) program, using the same arguments.
+pub(crate) fn restart() -> ! {
+    let program = env::current_exe()
+        .expect("Path to the current executable should be available");
+    
+    let mut command = Command::new(program);
+    command.args(env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = command.exec();
+        eprintln!("{} could not restart: {err}", "error:".red().bold());
+        process::exit(1);
+    }
+
+    #[cfg(not(unix))]
+    {
+        match command.status() {
+            Ok(status) => process::exit(status.code().unwrap_or(1)),
+            Err(err) => {
+                eprintln!("{} could not restart: {err}", "error:".red().bold());
+                process::exit(1);
+            }
+        }
+    }
+}